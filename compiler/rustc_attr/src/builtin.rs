@@ -5,6 +5,8 @@
 use rustc_errors::{struct_span_err, Applicability};
 use rustc_feature::{find_gated_cfg, is_builtin_attr_name, Features, GatedCfg};
 use rustc_macros::HashStable_Generic;
+use rustc_session::config::ExpectedValues;
+use rustc_session::lint::builtin::UNEXPECTED_CFGS;
 use rustc_session::parse::{feature_err, ParseSess};
 use rustc_session::Session;
 use rustc_span::hygiene::Transparency;
@@ -438,7 +440,12 @@ pub fn find_crate_name(sess: &Session, attrs: &[Attribute]) -> Option<Symbol> {
 }
 
 /// Tests if a cfg-pattern matches the cfg set
-pub fn cfg_matches(cfg: &ast::MetaItem, sess: &ParseSess, features: Option<&Features>) -> bool {
+pub fn cfg_matches(
+    cfg: &ast::MetaItem,
+    sess: &ParseSess,
+    lint_node_id: ast::NodeId,
+    features: Option<&Features>,
+) -> bool {
     eval_condition(cfg, sess, features, &mut |cfg| {
         try_gate_cfg(cfg, sess, features);
         let error = |span, msg| {
@@ -465,7 +472,28 @@ pub fn cfg_matches(cfg: &ast::MetaItem, sess: &ParseSess, features: Option<&Feat
             }
             MetaItemKind::NameValue(..) | MetaItemKind::Word => {
                 let ident = cfg.ident().expect("multi-segment cfg predicate");
-                sess.config.contains(&(ident.name, cfg.value_str()))
+                let name = ident.name;
+                let value = cfg.value_str();
+                match sess.check_config.expecteds.get(&name) {
+                    Some(ExpectedValues::Some(values)) if !values.contains(&value) => {
+                        sess.buffer_lint(
+                            UNEXPECTED_CFGS,
+                            cfg.span,
+                            lint_node_id,
+                            "unexpected `cfg` condition value",
+                        );
+                    }
+                    None if sess.check_config.exhaustive_names => {
+                        sess.buffer_lint(
+                            UNEXPECTED_CFGS,
+                            cfg.span,
+                            lint_node_id,
+                            "unexpected `cfg` condition name",
+                        );
+                    }
+                    _ => {}
+                }
+                sess.config.contains(&(name, value))
             }
         }
     })
@@ -556,6 +584,20 @@ pub fn eval_condition(
                 rustc_version >= min_version
             }
         }
+        ast::MetaItemKind::List(..) if cfg.name_or_empty() == sym::accessible => {
+            // `#[cfg]` attributes using `accessible` are expanded like `#[cfg_accessible]` once
+            // paths can be resolved, other uses cannot wait for name resolution.
+            try_gate_cfg(cfg, sess, features);
+            if features.map_or(true, |features| features.cfg_accessible) {
+                sess.span_diagnostic
+                    .struct_span_err(
+                        cfg.span,
+                        "`accessible` predicates are only supported in `#[cfg]` attributes on items",
+                    )
+                    .emit();
+            }
+            false
+        }
         ast::MetaItemKind::List(ref mis) => {
             for mi in mis.iter() {
                 if !mi.is_meta_item() {
@@ -571,12 +613,14 @@ pub fn eval_condition(
             // The unwraps below may look dangerous, but we've already asserted
             // that they won't fail with the loop above.
             match cfg.name_or_empty() {
-                sym::any => mis
-                    .iter()
-                    .any(|mi| eval_condition(mi.meta_item().unwrap(), sess, features, eval)),
-                sym::all => mis
-                    .iter()
-                    .all(|mi| eval_condition(mi.meta_item().unwrap(), sess, features, eval)),
+                // `any` and `all` don't short-circuit, so that every predicate gets checked
+                // against `--check-cfg` even when the result is already known.
+                sym::any => mis.iter().fold(false, |res, mi| {
+                    res | eval_condition(mi.meta_item().unwrap(), sess, features, eval)
+                }),
+                sym::all => mis.iter().fold(true, |res, mi| {
+                    res & eval_condition(mi.meta_item().unwrap(), sess, features, eval)
+                }),
                 sym::not => {
                     if mis.len() != 1 {
                         struct_span_err!(
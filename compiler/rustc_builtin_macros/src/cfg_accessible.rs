@@ -1,7 +1,10 @@
-//! Implementation of the `#[cfg_accessible(path)]` attribute macro.
+//! Implementation of the `#[cfg_accessible(path)]` attribute macro, which also expands
+//! `#[cfg]` attributes using `accessible(path)` predicates.
 
 use rustc_ast as ast;
+use rustc_attr as attr;
 use rustc_expand::base::{Annotatable, ExpandResult, ExtCtxt, Indeterminate, MultiItemModifier};
+use rustc_expand::config::parse_cfg;
 use rustc_feature::AttributeTemplate;
 use rustc_parse::validate_attr;
 use rustc_span::symbol::sym;
@@ -9,6 +12,10 @@
 
 crate struct Expander;
 
+/// Expands `#[cfg]` attributes using `accessible(path)` predicates, see
+/// `StripUnconfigured::is_deferred_cfg`.
+crate struct DeferredCfg;
+
 fn validate_input<'a>(ecx: &mut ExtCtxt<'_>, mi: &'a ast::MetaItem) -> Option<&'a ast::Path> {
     match mi.meta_item_list() {
         None => {}
@@ -27,6 +34,92 @@ fn validate_input<'a>(ecx: &mut ExtCtxt<'_>, mi: &'a ast::MetaItem) -> Option<&'
     None
 }
 
+/// Returns the operands of a well-formed `any`, `all` or `not` predicate.
+fn predicate_operands(mi: &ast::MetaItem) -> Option<Vec<&ast::MetaItem>> {
+    let list = mi.meta_item_list()?;
+    let operands = list.iter().map(|nmi| nmi.meta_item()).collect::<Option<Vec<_>>>()?;
+    match mi.name_or_empty() {
+        sym::any | sym::all => Some(operands),
+        sym::not if operands.len() == 1 => Some(operands),
+        _ => None,
+    }
+}
+
+/// Collects the `accessible(path)` predicates of `mi` in the order `eval_predicate` visits them.
+fn collect_accessible<'a>(mi: &'a ast::MetaItem, accessible: &mut Vec<&'a ast::MetaItem>) {
+    if mi.has_name(sym::accessible) && mi.meta_item_list().is_some() {
+        accessible.push(mi);
+    } else if let Some(operands) = predicate_operands(mi) {
+        operands.into_iter().for_each(|mi| collect_accessible(mi, accessible));
+    }
+}
+
+/// Evaluates a `cfg` predicate, taking the values of its `accessible(path)` predicates
+/// from `accessible`.
+fn eval_predicate(
+    ecx: &ExtCtxt<'_>,
+    mi: &ast::MetaItem,
+    accessible: &mut impl Iterator<Item = bool>,
+) -> bool {
+    if mi.has_name(sym::accessible) && mi.meta_item_list().is_some() {
+        return accessible.next().unwrap();
+    }
+    match predicate_operands(mi) {
+        // Like `cfg`, `any` and `all` don't short-circuit.
+        Some(operands) => match mi.name_or_empty() {
+            sym::any => operands
+                .into_iter()
+                .fold(false, |res, mi| res | eval_predicate(ecx, mi, accessible)),
+            sym::all => {
+                operands.into_iter().fold(true, |res, mi| res & eval_predicate(ecx, mi, accessible))
+            }
+            _ => !eval_predicate(ecx, operands[0], accessible),
+        },
+        None => attr::cfg_matches(
+            mi,
+            &ecx.sess.parse_sess,
+            ecx.current_expansion.lint_node_id,
+            ecx.ecfg.features,
+        ),
+    }
+}
+
+/// Expands `#[cfg(predicate)]` where `predicate` uses `accessible(path)`.
+fn expand_predicate(
+    ecx: &mut ExtCtxt<'_>,
+    span: Span,
+    predicate: &ast::MetaItem,
+    item: Annotatable,
+) -> ExpandResult<Vec<Annotatable>, Annotatable> {
+    let mut accessible = Vec::new();
+    collect_accessible(predicate, &mut accessible);
+    let paths: Option<Vec<_>> = accessible.into_iter().map(|mi| validate_input(ecx, mi)).collect();
+    let paths = match paths {
+        Some(paths) => paths,
+        None => return ExpandResult::Ready(Vec::new()),
+    };
+
+    // Resolve all the paths before evaluating anything, so that a retried expansion
+    // doesn't report the diagnostics of the other predicates again.
+    let mut values = Vec::new();
+    for path in paths {
+        match ecx.resolver.cfg_accessible(ecx.current_expansion.id, path) {
+            Ok(value) => values.push(value),
+            Err(Indeterminate) if ecx.force_mode => {
+                ecx.span_err(span, "cannot determine whether the path is accessible or not");
+                return ExpandResult::Ready(vec![item]);
+            }
+            Err(Indeterminate) => return ExpandResult::Retry(item),
+        }
+    }
+
+    if eval_predicate(ecx, predicate, &mut values.into_iter()) {
+        ExpandResult::Ready(vec![item])
+    } else {
+        ExpandResult::Ready(Vec::new())
+    }
+}
+
 impl MultiItemModifier for Expander {
     fn expand(
         &self,
@@ -60,3 +153,18 @@ fn expand(
         }
     }
 }
+
+impl MultiItemModifier for DeferredCfg {
+    fn expand(
+        &self,
+        ecx: &mut ExtCtxt<'_>,
+        span: Span,
+        meta_item: &ast::MetaItem,
+        item: Annotatable,
+    ) -> ExpandResult<Vec<Annotatable>, Annotatable> {
+        match parse_cfg(meta_item, ecx.sess) {
+            Some(predicate) => expand_predicate(ecx, span, predicate, item),
+            None => ExpandResult::Ready(vec![item]),
+        }
+    }
+}
@@ -8,7 +8,7 @@
 use rustc_ast::{AstLike, Attribute};
 use rustc_expand::base::{Annotatable, ExtCtxt};
 use rustc_expand::config::StripUnconfigured;
-use rustc_expand::configure;
+use rustc_expand::{configure, configure_item};
 use rustc_parse::parser::ForceCollect;
 use rustc_session::utils::FlattenNonterminals;
 
@@ -33,6 +33,7 @@
             sess: ecx.sess,
             features: ecx.ecfg.features,
             config_tokens: true,
+            lint_node_id: ecx.current_expansion.lint_node_id,
         },
     }
     .configure_annotatable(annotatable)
@@ -122,6 +123,10 @@ fn configure<T: AstLike>(&mut self, node: T) -> Option<T> {
         self.cfg.configure(node)
     }
 
+    fn configure_item<T: AstLike>(&mut self, node: T) -> Option<T> {
+        self.cfg.configure_item(node)
+    }
+
     fn configure_annotatable(&mut self, mut annotatable: Annotatable) -> Option<Annotatable> {
         // Tokenizing and re-parsing the `Annotatable` can have a significant
         // performance impact, so try to avoid it if possible
@@ -220,26 +225,30 @@ fn flat_map_generic_param(
     }
 
     fn flat_map_stmt(&mut self, stmt: ast::Stmt) -> SmallVec<[ast::Stmt; 1]> {
-        mut_visit::noop_flat_map_stmt(configure!(self, stmt), self)
+        let stmt = match stmt.kind {
+            ast::StmtKind::Item(..) => configure_item!(self, stmt),
+            _ => configure!(self, stmt),
+        };
+        mut_visit::noop_flat_map_stmt(stmt, self)
     }
 
     fn flat_map_item(&mut self, item: P<ast::Item>) -> SmallVec<[P<ast::Item>; 1]> {
-        mut_visit::noop_flat_map_item(configure!(self, item), self)
+        mut_visit::noop_flat_map_item(configure_item!(self, item), self)
     }
 
     fn flat_map_impl_item(&mut self, item: P<ast::AssocItem>) -> SmallVec<[P<ast::AssocItem>; 1]> {
-        mut_visit::noop_flat_map_assoc_item(configure!(self, item), self)
+        mut_visit::noop_flat_map_assoc_item(configure_item!(self, item), self)
     }
 
     fn flat_map_trait_item(&mut self, item: P<ast::AssocItem>) -> SmallVec<[P<ast::AssocItem>; 1]> {
-        mut_visit::noop_flat_map_assoc_item(configure!(self, item), self)
+        mut_visit::noop_flat_map_assoc_item(configure_item!(self, item), self)
     }
 
     fn flat_map_foreign_item(
         &mut self,
         foreign_item: P<ast::ForeignItem>,
     ) -> SmallVec<[P<ast::ForeignItem>; 1]> {
-        mut_visit::noop_flat_map_foreign_item(configure!(self, foreign_item), self)
+        mut_visit::noop_flat_map_foreign_item(configure_item!(self, foreign_item), self)
     }
 
     fn flat_map_arm(&mut self, arm: ast::Arm) -> SmallVec<[ast::Arm; 1]> {
@@ -97,6 +97,11 @@ pub fn register_builtin_macros(resolver: &mut dyn ResolverExpand) {
         test_case: test::expand_test_case,
     }
 
+    // Not declared with `#[rustc_builtin_macro]`, the resolver uses it to expand `#[cfg]`
+    // attributes with `accessible(path)` predicates.
+    let deferred_cfg = Box::new(cfg_accessible::DeferredCfg);
+    register(sym::deferred_cfg, SyntaxExtensionKind::LegacyAttr(deferred_cfg));
+
     register_derive! {
         Clone: clone::expand_deriving_clone,
         Copy: bounds::expand_deriving_copy,
@@ -9,7 +9,7 @@
 use crate::value::Value;
 
 use cstr::cstr;
-use rustc_codegen_ssa::base::wants_msvc_seh;
+use rustc_codegen_ssa::base::{wants_msvc_seh, wants_new_eh_instructions, wants_wasm_eh};
 use rustc_codegen_ssa::traits::*;
 use rustc_data_structures::base_n;
 use rustc_data_structures::fx::FxHashMap;
@@ -379,12 +379,15 @@ fn eh_personality(&self) -> &'ll Value {
         // `rust_eh_personality` function, but rather we wired it up to the
         // CRT's custom personality function, which forces LLVM to consider
         // landing pads as "landing pads for SEH".
+        //
+        // Wasm exception handling works the same way: LLVM keys off the name
+        // `__gxx_wasm_personality_v0` to emit `try`/`catch` instructions.
         if let Some(llpersonality) = self.eh_personality.get() {
             return llpersonality;
         }
         let tcx = self.tcx;
         let llfn = match tcx.lang_items().eh_personality() {
-            Some(def_id) if !wants_msvc_seh(self.sess()) => self.get_fn_addr(
+            Some(def_id) if !wants_new_eh_instructions(self.sess()) => self.get_fn_addr(
                 ty::Instance::resolve(
                     tcx,
                     ty::ParamEnv::reveal_all(),
@@ -397,6 +400,8 @@ fn eh_personality(&self) -> &'ll Value {
             _ => {
                 let name = if wants_msvc_seh(self.sess()) {
                     "__CxxFrameHandler3"
+                } else if wants_wasm_eh(self.sess()) {
+                    "__gxx_wasm_personality_v0"
                 } else {
                     "rust_eh_personality"
                 };
@@ -533,6 +538,10 @@ macro_rules! mk_struct {
         ifn!("llvm.wasm.trunc.signed.i64.f32", fn(t_f32) -> t_i64);
         ifn!("llvm.wasm.trunc.signed.i64.f64", fn(t_f64) -> t_i64);
 
+        let t_token = self.type_token();
+        ifn!("llvm.wasm.get.exception", fn(t_token) -> i8p);
+        ifn!("llvm.wasm.get.ehselector", fn(t_token) -> t_i32);
+
         ifn!("llvm.fptosi.sat.i8.f32", fn(t_f32) -> t_i8);
         ifn!("llvm.fptosi.sat.i16.f32", fn(t_f32) -> t_i16);
         ifn!("llvm.fptosi.sat.i32.f32", fn(t_f32) -> t_i32);
@@ -960,6 +960,7 @@ pub fn LLVMStructTypeInContext(
     // Operations on other types
     pub fn LLVMVoidTypeInContext(C: &Context) -> &Type;
     pub fn LLVMRustMetadataTypeInContext(C: &Context) -> &Type;
+    pub fn LLVMRustTokenTypeInContext(C: &Context) -> &Type;
 
     // Operations on all values
     pub fn LLVMTypeOf(Val: &Value) -> &Type;
@@ -1,6 +1,7 @@
 use crate::back::write::create_informational_target_machine;
 use crate::{llvm, llvm_util};
 use libc::c_int;
+use rustc_codegen_ssa::base::wants_wasm_eh;
 use rustc_codegen_ssa::target_features::supported_target_features;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_metadata::dynamic_lib::DynamicLibrary;
@@ -110,6 +111,10 @@ fn llvm_arg_to_arg_name(full_arg: &str) -> &str {
             add("-enable-emscripten-cxx-exceptions", false);
         }
 
+        if wants_wasm_eh(sess) && sess.panic_strategy() == PanicStrategy::Unwind {
+            add("-wasm-enable-eh", false);
+        }
+
         // HACK(eddyb) LLVM inserts `llvm.assume` calls to preserve align attributes
         // during inlining. Unfortunately these may block other optimizations.
         add("-preserve-alignment-assumptions-during-inlining=false", false);
@@ -394,6 +399,12 @@ pub fn llvm_global_features(sess: &Session) -> Vec<String> {
     // Features implied by an implicit or explicit `--target`.
     features.extend(sess.target.features.split(',').filter_map(&filter));
 
+    // Unwinding on wasm is implemented with the exception-handling proposal,
+    // so `-Cpanic=unwind` implies it.
+    if wants_wasm_eh(sess) && sess.panic_strategy() == PanicStrategy::Unwind {
+        features.push("+exception-handling".to_string());
+    }
+
     // -Ctarget-features
     features.extend(sess.opts.cg.target_feature.split(',').filter_map(&filter));
 
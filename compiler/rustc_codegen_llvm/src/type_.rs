@@ -56,6 +56,10 @@ impl CodegenCx<'ll, 'tcx> {
         unsafe { llvm::LLVMRustMetadataTypeInContext(self.llcx) }
     }
 
+    crate fn type_token(&self) -> &'ll Type {
+        unsafe { llvm::LLVMRustTokenTypeInContext(self.llcx) }
+    }
+
     ///x Creates an integer type with the given number of bits, e.g., i24
     crate fn type_ix(&self, num_bits: u64) -> &'ll Type {
         unsafe { llvm::LLVMIntTypeInContext(self.llcx, num_bits as c_uint) }
@@ -39,7 +39,7 @@ fn funclet<'b, Bx: BuilderMethods<'a, 'tcx>>(
         fx: &'b mut FunctionCx<'a, 'tcx, Bx>,
     ) -> Option<&'b Bx::Funclet> {
         let funclet_bb = self.funclet_bb?;
-        if base::wants_msvc_seh(fx.cx.tcx().sess) {
+        if base::wants_new_eh_instructions(fx.cx.tcx().sess) {
             // If `landing_pad_for` hasn't been called yet to create the `Funclet`,
             // it has to be now. This may not seem necessary, as RPO should lead
             // to all the unwind edges being visited (and so to `landing_pad_for`
@@ -72,7 +72,9 @@ fn lltarget<Bx: BuilderMethods<'a, 'tcx>>(
         let target_funclet = fx.cleanup_kinds[target].funclet_bb(target);
         match (self.funclet_bb, target_funclet) {
             (None, None) => (lltarget, false),
-            (Some(f), Some(t_f)) if f == t_f || !base::wants_msvc_seh(fx.cx.tcx().sess) => {
+            (Some(f), Some(t_f))
+                if f == t_f || !base::wants_new_eh_instructions(fx.cx.tcx().sess) =>
+            {
                 (lltarget, false)
             }
             // jump *into* cleanup - need a landing pad if GNU, cleanup pad if MSVC
@@ -1219,7 +1221,7 @@ fn landing_pad_for(&mut self, bb: mir::BasicBlock) -> Bx::BasicBlock {
     // FIXME(eddyb) rename this to `eh_pad_for_uncached`.
     fn landing_pad_for_uncached(&mut self, bb: mir::BasicBlock) -> Bx::BasicBlock {
         let llbb = self.llbb(bb);
-        if base::wants_msvc_seh(self.cx.sess()) {
+        if base::wants_new_eh_instructions(self.cx.sess()) {
             let funclet;
             let ret_llbb;
             match self.mir[bb].terminator.as_ref().map(|t| &t.kind) {
@@ -1255,11 +1257,18 @@ fn landing_pad_for_uncached(&mut self, bb: mir::BasicBlock) -> Bx::BasicBlock {
                     // C++ personality function, but `catch (...)` has no type so
                     // it's null. The 64 here is actually a bitfield which
                     // represents that this is a catch-all block.
+                    //
+                    // On wasm the catch-all clause of `__gxx_wasm_personality_v0`
+                    // is just a single null.
                     let null = cp_bx.const_null(
                         cp_bx.type_i8p_ext(cp_bx.cx().data_layout().instruction_address_space),
                     );
-                    let sixty_four = cp_bx.const_i32(64);
-                    funclet = cp_bx.catch_pad(cs, &[null, sixty_four, null]);
+                    funclet = if base::wants_msvc_seh(self.cx.sess()) {
+                        let sixty_four = cp_bx.const_i32(64);
+                        cp_bx.catch_pad(cs, &[null, sixty_four, null])
+                    } else {
+                        cp_bx.catch_pad(cs, &[null])
+                    };
                     cp_bx.br(llbb);
                 }
                 _ => {
@@ -79,24 +79,42 @@
 //! invocation) and allocate the corresponding strings together with a mapping
 //! for `DepNodeIndex as StringId`.
 //!
+//!
+//! ## In-memory Recording
+//!
+//! `measureme` only writes events to disk, so for `-Z self-profile-format=chrome`
+//! and `-Z self-profile-summarize` the `SelfProfiler` additionally keeps a
+//! copy of every interval and instant event in memory (see `EventRecorder`).
+//! Each thread appends its events to a buffer of its own, so that recording
+//! does not contend on a lock shared by all threads.
+//! These events are labeled with the `&'static str` names of the generic
+//! activities and queries they belong to, and are turned into a Chrome
+//! `trace_event` file and/or a per-item summary when the profiler is dropped.
+//!
 //! [mm]: https://github.com/rust-lang/measureme/
 
 use crate::cold_path;
 use crate::fx::FxHashMap;
 
+use rustc_serialize::json::{self, Json, ToJson};
+
 use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
 use std::convert::Into;
 use std::error::Error;
 use std::fs;
-use std::path::Path;
+use std::mem;
+use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 pub use measureme::EventId;
 use measureme::{EventIdBuilder, Profiler, SerializableString, StringId};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 
 bitflags::bitflags! {
     struct EventFilter: u32 {
@@ -138,6 +156,25 @@ struct EventFilter: u32 {
     ("incr-result-hashing", EventFilter::INCR_RESULT_HASHING),
 ];
 
+/// The format in which `-Z self-profile` emits its data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelfProfileFormat {
+    /// Only the raw `measureme` event data.
+    Raw,
+    /// The raw `measureme` event data plus a Chrome `trace_event` JSON file that
+    /// can be loaded into `chrome://tracing` or Perfetto.
+    Chrome,
+}
+
+/// The format in which `-Z time-passes` prints its timing entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimePassesFormat {
+    /// Human readable text, one line per pass.
+    Text,
+    /// One JSON object per line and pass.
+    Json,
+}
+
 /// Something that uniquely identifies a query invocation.
 pub struct QueryInvocationId(pub u32);
 
@@ -159,6 +196,9 @@ pub struct SelfProfilerRef {
 
     // Print extra verbose generic activities to stdout
     print_extra_verbose_generic_activities: bool,
+
+    // The format in which verbose generic activities are printed
+    time_passes_format: TimePassesFormat,
 }
 
 impl SelfProfilerRef {
@@ -166,6 +206,7 @@ pub fn new(
         profiler: Option<Arc<SelfProfiler>>,
         print_verbose_generic_activities: bool,
         print_extra_verbose_generic_activities: bool,
+        time_passes_format: TimePassesFormat,
     ) -> SelfProfilerRef {
         // If there is no SelfProfiler then the filter mask is set to NONE,
         // ensuring that nothing ever tries to actually access it.
@@ -177,6 +218,7 @@ pub fn new(
             event_filter_mask,
             print_verbose_generic_activities,
             print_extra_verbose_generic_activities,
+            time_passes_format,
         }
     }
 
@@ -217,7 +259,11 @@ pub fn verbose_generic_activity<'a>(
         let message =
             if self.print_verbose_generic_activities { Some(event_label.to_owned()) } else { None };
 
-        VerboseTimingGuard::start(message, self.generic_activity(event_label))
+        VerboseTimingGuard::start(
+            message,
+            self.time_passes_format,
+            self.generic_activity(event_label),
+        )
     }
 
     /// Start profiling a extra verbose generic activity. Profiling continues until the
@@ -238,7 +284,11 @@ pub fn extra_verbose_generic_activity<'a, A>(
             None
         };
 
-        VerboseTimingGuard::start(message, self.generic_activity_with_arg(event_label, event_arg))
+        VerboseTimingGuard::start(
+            message,
+            self.time_passes_format,
+            self.generic_activity_with_arg(event_label, event_arg),
+        )
     }
 
     /// Start profiling a generic activity. Profiling continues until the
@@ -246,9 +296,15 @@ pub fn extra_verbose_generic_activity<'a, A>(
     #[inline(always)]
     pub fn generic_activity(&self, event_label: &'static str) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            let event_label = profiler.get_or_alloc_cached_string(event_label);
-            let event_id = EventId::from_label(event_label);
-            TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+            let event_label_id = profiler.get_or_alloc_cached_string(event_label);
+            let event_id = EventId::from_label(event_label_id);
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.generic_activity_event_kind,
+                event_id,
+                RecordedKind::GenericActivity,
+                RecordedLabel::Activity(event_label),
+            )
         })
     }
 
@@ -257,7 +313,13 @@ pub fn generic_activity(&self, event_label: &'static str) -> TimingGuard<'_> {
     #[inline(always)]
     pub fn generic_activity_with_event_id(&self, event_id: EventId) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
-            TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.generic_activity_event_kind,
+                event_id,
+                RecordedKind::GenericActivity,
+                RecordedLabel::None,
+            )
         })
     }
 
@@ -274,14 +336,20 @@ pub fn generic_activity_with_arg<A>(
     {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
             let builder = EventIdBuilder::new(&profiler.profiler);
-            let event_label = profiler.get_or_alloc_cached_string(event_label);
+            let event_label_id = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_arg = profiler.get_or_alloc_cached_string(event_arg);
-                builder.from_label_and_arg(event_label, event_arg)
+                builder.from_label_and_arg(event_label_id, event_arg)
             } else {
-                builder.from_label(event_label)
+                builder.from_label(event_label_id)
             };
-            TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.generic_activity_event_kind,
+                event_id,
+                RecordedKind::GenericActivity,
+                RecordedLabel::Activity(event_label),
+            )
         })
     }
 
@@ -293,17 +361,23 @@ pub fn generic_activity_with_args(
     ) -> TimingGuard<'_> {
         self.exec(EventFilter::GENERIC_ACTIVITIES, |profiler| {
             let builder = EventIdBuilder::new(&profiler.profiler);
-            let event_label = profiler.get_or_alloc_cached_string(event_label);
+            let event_label_id = profiler.get_or_alloc_cached_string(event_label);
             let event_id = if profiler.event_filter_mask.contains(EventFilter::FUNCTION_ARGS) {
                 let event_args: Vec<_> = event_args
                     .iter()
                     .map(|s| profiler.get_or_alloc_cached_string(&s[..]))
                     .collect();
-                builder.from_label_and_args(event_label, &event_args)
+                builder.from_label_and_args(event_label_id, &event_args)
             } else {
-                builder.from_label(event_label)
+                builder.from_label(event_label_id)
             };
-            TimingGuard::start(profiler, profiler.generic_activity_event_kind, event_id)
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.generic_activity_event_kind,
+                event_id,
+                RecordedKind::GenericActivity,
+                RecordedLabel::Activity(event_label),
+            )
         })
     }
 
@@ -312,7 +386,13 @@ pub fn generic_activity_with_args(
     #[inline(always)]
     pub fn query_provider(&self) -> TimingGuard<'_> {
         self.exec(EventFilter::QUERY_PROVIDERS, |profiler| {
-            TimingGuard::start(profiler, profiler.query_event_kind, EventId::INVALID)
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.query_event_kind,
+                EventId::INVALID,
+                RecordedKind::Query,
+                RecordedLabel::None,
+            )
         })
     }
 
@@ -321,6 +401,7 @@ pub fn query_provider(&self) -> TimingGuard<'_> {
     pub fn query_cache_hit(&self, query_invocation_id: QueryInvocationId) {
         self.instant_query_event(
             |profiler| profiler.query_cache_hit_event_kind,
+            RecordedKind::QueryCacheHit,
             query_invocation_id,
             EventFilter::QUERY_CACHE_HITS,
         );
@@ -332,7 +413,13 @@ pub fn query_cache_hit(&self, query_invocation_id: QueryInvocationId) {
     #[inline(always)]
     pub fn query_blocked(&self) -> TimingGuard<'_> {
         self.exec(EventFilter::QUERY_BLOCKED, |profiler| {
-            TimingGuard::start(profiler, profiler.query_blocked_event_kind, EventId::INVALID)
+            TimingGuard::start_recorded(
+                profiler,
+                profiler.query_blocked_event_kind,
+                EventId::INVALID,
+                RecordedKind::QueryBlocked,
+                RecordedLabel::None,
+            )
         })
     }
 
@@ -342,10 +429,12 @@ pub fn query_blocked(&self) -> TimingGuard<'_> {
     #[inline(always)]
     pub fn incr_cache_loading(&self) -> TimingGuard<'_> {
         self.exec(EventFilter::INCR_CACHE_LOADS, |profiler| {
-            TimingGuard::start(
+            TimingGuard::start_recorded(
                 profiler,
                 profiler.incremental_load_result_event_kind,
                 EventId::INVALID,
+                RecordedKind::IncrementalLoadResult,
+                RecordedLabel::None,
             )
         })
     }
@@ -355,10 +444,12 @@ pub fn incr_cache_loading(&self) -> TimingGuard<'_> {
     #[inline(always)]
     pub fn incr_result_hashing(&self) -> TimingGuard<'_> {
         self.exec(EventFilter::INCR_RESULT_HASHING, |profiler| {
-            TimingGuard::start(
+            TimingGuard::start_recorded(
                 profiler,
                 profiler.incremental_result_hashing_event_kind,
                 EventId::INVALID,
+                RecordedKind::IncrementalResultHashing,
+                RecordedLabel::None,
             )
         })
     }
@@ -367,6 +458,7 @@ pub fn incr_result_hashing(&self) -> TimingGuard<'_> {
     fn instant_query_event(
         &self,
         event_kind: fn(&SelfProfiler) -> StringId,
+        recorded_kind: RecordedKind,
         query_invocation_id: QueryInvocationId,
         event_filter: EventFilter,
     ) {
@@ -380,6 +472,12 @@ fn instant_query_event(
                 thread_id,
             );
 
+            if let Some(recorder) = &profiler.recorder {
+                let now = Instant::now();
+                let label = RecordedLabel::QueryInvocation(query_invocation_id.0);
+                recorder.record(recorded_kind, label, thread_id, now, now);
+            }
+
             TimingGuard::none()
         }));
     }
@@ -425,6 +523,10 @@ pub struct SelfProfiler {
     incremental_result_hashing_event_kind: StringId,
     query_blocked_event_kind: StringId,
     query_cache_hit_event_kind: StringId,
+
+    /// Keeps a copy of all events in memory if they are needed for
+    /// `-Z self-profile-format=chrome` or `-Z self-profile-summarize`.
+    recorder: Option<EventRecorder>,
 }
 
 impl SelfProfiler {
@@ -432,6 +534,8 @@ pub fn new(
         output_directory: &Path,
         crate_name: Option<&str>,
         event_filters: &Option<Vec<String>>,
+        format: SelfProfileFormat,
+        summarize: bool,
     ) -> Result<SelfProfiler, Box<dyn Error + Send + Sync>> {
         fs::create_dir_all(output_directory)?;
 
@@ -440,6 +544,17 @@ pub fn new(
         let path = output_directory.join(&filename);
         let profiler = Profiler::new(&path)?;
 
+        let recorder = if format == SelfProfileFormat::Chrome || summarize {
+            let trace_path = if format == SelfProfileFormat::Chrome {
+                Some(output_directory.join(format!("{}-{}.json", crate_name, process::id())))
+            } else {
+                None
+            };
+            Some(EventRecorder::new(trace_path, summarize))
+        } else {
+            None
+        };
+
         let query_event_kind = profiler.alloc_string("Query");
         let generic_activity_event_kind = profiler.alloc_string("GenericActivity");
         let incremental_load_result_event_kind = profiler.alloc_string("IncrementalLoadResult");
@@ -481,6 +596,11 @@ pub fn new(
             event_filter_mask = EventFilter::DEFAULT;
         }
 
+        // The summary reports per-query cache-hit ratios, which need the cache hits.
+        if summarize {
+            event_filter_mask |= EventFilter::QUERY_CACHE_HITS;
+        }
+
         Ok(SelfProfiler {
             profiler,
             event_filter_mask,
@@ -491,6 +611,7 @@ pub fn new(
             incremental_result_hashing_event_kind,
             query_blocked_event_kind,
             query_cache_hit_event_kind,
+            recorder,
         })
     }
 
@@ -546,13 +667,337 @@ pub fn query_key_recording_enabled(&self) -> bool {
         self.event_filter_mask.contains(EventFilter::QUERY_KEYS)
     }
 
+    /// Returns `true` if events are also kept in memory, in which case the
+    /// query engine has to report the query name of each invocation via
+    /// `map_query_invocation_ids_to_query_name`.
+    pub fn event_recording_enabled(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub fn map_query_invocation_ids_to_query_name<I>(&self, from: I, query_name: &'static str)
+    where
+        I: Iterator<Item = QueryInvocationId>,
+    {
+        if let Some(recorder) = &self.recorder {
+            let mut query_names = recorder.query_names.lock();
+            query_names.extend(from.map(|qid| (qid.0, query_name)));
+        }
+    }
+
     pub fn event_id_builder(&self) -> EventIdBuilder<'_> {
         EventIdBuilder::new(&self.profiler)
     }
 }
 
+/// The kind of an event kept in memory by the `EventRecorder`. These mirror
+/// the `*_event_kind` strings allocated by the `SelfProfiler`.
+#[derive(Clone, Copy, PartialEq)]
+enum RecordedKind {
+    GenericActivity,
+    Query,
+    QueryBlocked,
+    QueryCacheHit,
+    IncrementalLoadResult,
+    IncrementalResultHashing,
+    /// Events started through the public `TimingGuard::start`, e.g. LLVM passes.
+    Other,
+}
+
+impl RecordedKind {
+    fn name(self) -> &'static str {
+        match self {
+            RecordedKind::GenericActivity => "GenericActivity",
+            RecordedKind::Query => "Query",
+            RecordedKind::QueryBlocked => "QueryBlocked",
+            RecordedKind::QueryCacheHit => "QueryCacheHit",
+            RecordedKind::IncrementalLoadResult => "IncrementalLoadResult",
+            RecordedKind::IncrementalResultHashing => "IncrementalResultHashing",
+            RecordedKind::Other => "Other",
+        }
+    }
+}
+
+/// What an event kept in memory refers to. Query events only learn their
+/// `QueryInvocationId` when they finish, and the corresponding query name is
+/// only known once the query strings are allocated at the end of the session.
+#[derive(Clone, Copy)]
+enum RecordedLabel {
+    Activity(&'static str),
+    QueryInvocation(u32),
+    None,
+}
+
+struct RecordedEvent {
+    kind: RecordedKind,
+    label: RecordedLabel,
+    thread_id: u32,
+    /// Offsets from the start of the profiling session.
+    start: Duration,
+    end: Duration,
+}
+
+impl RecordedEvent {
+    fn is_instant(&self) -> bool {
+        self.kind == RecordedKind::QueryCacheHit
+    }
+
+    fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Per-item totals printed by `-Z self-profile-summarize`.
+#[derive(Default)]
+struct SummaryEntry {
+    self_time: Duration,
+    time: Duration,
+    count: u64,
+    cache_hits: u64,
+    is_query: bool,
+}
+
+type ThreadEvents = Arc<Mutex<Vec<RecordedEvent>>>;
+
+/// Distinguishes the `EventRecorder`s of different sessions in `THREAD_EVENTS`.
+static NEXT_RECORDER_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The event buffer of the current thread, along with the id of the
+    /// `EventRecorder` it belongs to.
+    static THREAD_EVENTS: RefCell<Option<(usize, ThreadEvents)>> = RefCell::new(None);
+}
+
+/// Keeps a copy of the profiling events in memory so that they can be
+/// written as a Chrome trace and/or summarized when the session ends.
+struct EventRecorder {
+    id: usize,
+    /// Where to write the Chrome `trace_event` JSON, if requested.
+    trace_path: Option<PathBuf>,
+    summarize: bool,
+    start_time: Instant,
+    /// The event buffers of all threads that recorded events. Each buffer is
+    /// only locked by its own thread until the session ends.
+    thread_events: Mutex<Vec<ThreadEvents>>,
+    query_names: Mutex<FxHashMap<u32, &'static str>>,
+}
+
+impl EventRecorder {
+    fn new(trace_path: Option<PathBuf>, summarize: bool) -> EventRecorder {
+        EventRecorder {
+            id: NEXT_RECORDER_ID.fetch_add(1, Ordering::Relaxed),
+            trace_path,
+            summarize,
+            start_time: Instant::now(),
+            thread_events: Mutex::new(Vec::new()),
+            query_names: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    fn record(
+        &self,
+        kind: RecordedKind,
+        label: RecordedLabel,
+        thread_id: u32,
+        start: Instant,
+        end: Instant,
+    ) {
+        let start = start.saturating_duration_since(self.start_time);
+        let end = end.saturating_duration_since(self.start_time);
+        let event = RecordedEvent { kind, label, thread_id, start, end };
+
+        THREAD_EVENTS.with(|thread_events| {
+            let mut thread_events = thread_events.borrow_mut();
+            if !matches!(&*thread_events, Some((id, _)) if *id == self.id) {
+                let events = ThreadEvents::default();
+                self.thread_events.lock().push(Arc::clone(&events));
+                *thread_events = Some((self.id, events));
+            }
+            thread_events.as_ref().unwrap().1.lock().push(event);
+        });
+    }
+
+    fn label_name(
+        query_names: &FxHashMap<u32, &'static str>,
+        event: &RecordedEvent,
+    ) -> &'static str {
+        match event.label {
+            RecordedLabel::Activity(label) => label,
+            RecordedLabel::QueryInvocation(id) => {
+                query_names.get(&id).copied().unwrap_or_else(|| event.kind.name())
+            }
+            RecordedLabel::None => event.kind.name(),
+        }
+    }
+
+    /// Computes the self time of each interval event, i.e. its duration minus
+    /// the durations of the events directly nested in it on the same thread.
+    fn self_times(events: &[RecordedEvent]) -> Vec<Duration> {
+        let mut self_times: Vec<_> = events.iter().map(|event| event.duration()).collect();
+
+        let mut order: Vec<usize> =
+            (0..events.len()).filter(|&i| !events[i].is_instant()).collect();
+        order.sort_by_key(|&i| (events[i].thread_id, events[i].start, Reverse(events[i].end)));
+
+        let mut stack: Vec<usize> = Vec::new();
+        for i in order {
+            let event = &events[i];
+            while let Some(&parent) = stack.last() {
+                let parent = &events[parent];
+                if parent.thread_id == event.thread_id && event.end <= parent.end {
+                    break;
+                }
+                stack.pop();
+            }
+            if let Some(&parent) = stack.last() {
+                self_times[parent] = self_times[parent].saturating_sub(event.duration());
+            }
+            stack.push(i);
+        }
+
+        self_times
+    }
+
+    fn write_chrome_trace(
+        path: &Path,
+        events: &[RecordedEvent],
+        query_names: &FxHashMap<u32, &'static str>,
+    ) -> std::io::Result<()> {
+        let to_micros = |d: Duration| Json::F64(d.as_secs_f64() * 1_000_000.0);
+        let pid = process::id();
+
+        let trace_events: json::Array = events
+            .iter()
+            .map(|event| {
+                let mut obj = json::Object::new();
+                let name = EventRecorder::label_name(query_names, event);
+                obj.insert("name".to_string(), name.to_json());
+                obj.insert("cat".to_string(), event.kind.name().to_json());
+                obj.insert("ts".to_string(), to_micros(event.start));
+                obj.insert("pid".to_string(), pid.to_json());
+                obj.insert("tid".to_string(), event.thread_id.to_json());
+                if event.is_instant() {
+                    obj.insert("ph".to_string(), "i".to_json());
+                    obj.insert("s".to_string(), "t".to_json());
+                } else {
+                    obj.insert("ph".to_string(), "X".to_json());
+                    obj.insert("dur".to_string(), to_micros(event.duration()));
+                }
+                Json::Object(obj)
+            })
+            .collect();
+
+        let mut trace = json::Object::new();
+        trace.insert("traceEvents".to_string(), Json::Array(trace_events));
+        trace.insert("displayTimeUnit".to_string(), "ms".to_json());
+        fs::write(path, Json::Object(trace).to_string())
+    }
+
+    fn print_summary(events: &[RecordedEvent], query_names: &FxHashMap<u32, &'static str>) {
+        let self_times = EventRecorder::self_times(events);
+
+        let mut entries: FxHashMap<&'static str, SummaryEntry> = FxHashMap::default();
+        for (event, &self_time) in events.iter().zip(&self_times) {
+            let entry = entries.entry(EventRecorder::label_name(query_names, event)).or_default();
+            match event.kind {
+                RecordedKind::QueryCacheHit => {
+                    entry.cache_hits += 1;
+                    entry.is_query = true;
+                    continue;
+                }
+                RecordedKind::Query => {
+                    entry.count += 1;
+                    entry.time += event.duration();
+                    entry.is_query = true;
+                }
+                RecordedKind::GenericActivity | RecordedKind::Other => {
+                    entry.count += 1;
+                    entry.time += event.duration();
+                }
+                // Time spent blocked on or loading a query is attributed to
+                // that query, but does not count as an invocation.
+                RecordedKind::QueryBlocked
+                | RecordedKind::IncrementalLoadResult
+                | RecordedKind::IncrementalResultHashing => {}
+            }
+            entry.self_time += self_time;
+        }
+
+        let total_self_time: Duration = entries.values().map(|entry| entry.self_time).sum();
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by_key(|&(name, ref entry)| (Reverse(entry.self_time), name));
+
+        eprintln!(
+            "{:<50} {:>12} {:>8} {:>12} {:>10} {:>10} {:>9}",
+            "Item", "Self time", "% total", "Time", "Item count", "Cache hits", "Hit ratio"
+        );
+        for (name, entry) in entries {
+            let percent = if total_self_time == Duration::ZERO {
+                0.0
+            } else {
+                entry.self_time.as_secs_f64() / total_self_time.as_secs_f64() * 100.0
+            };
+            let hit_ratio = if entry.is_query && entry.count + entry.cache_hits > 0 {
+                let ratio = entry.cache_hits as f64 / (entry.count + entry.cache_hits) as f64;
+                format!("{:.2}%", ratio * 100.0)
+            } else {
+                "-".to_string()
+            };
+            eprintln!(
+                "{:<50} {:>12} {:>8.3} {:>12} {:>10} {:>10} {:>9}",
+                name,
+                format!("{:.2?}", entry.self_time),
+                percent,
+                format!("{:.2?}", entry.time),
+                entry.count,
+                entry.cache_hits,
+                hit_ratio,
+            );
+        }
+        eprintln!("Total self time: {:.2?}", total_self_time);
+    }
+}
+
+impl Drop for EventRecorder {
+    fn drop(&mut self) {
+        let events: Vec<_> = self
+            .thread_events
+            .get_mut()
+            .iter()
+            .flat_map(|thread_events| mem::take(&mut *thread_events.lock()))
+            .collect();
+        let events = &events[..];
+        let query_names = self.query_names.get_mut();
+
+        if let Some(path) = &self.trace_path {
+            if let Err(e) = EventRecorder::write_chrome_trace(path, events, query_names) {
+                eprintln!("failed to write self-profile trace to `{}`: {}", path.display(), e);
+            }
+        }
+
+        if self.summarize {
+            EventRecorder::print_summary(events, query_names);
+        }
+    }
+}
+
+/// Records the time span of an event in the `EventRecorder` when dropped.
+struct RecordingGuard<'a> {
+    recorder: &'a EventRecorder,
+    kind: RecordedKind,
+    label: RecordedLabel,
+    thread_id: u32,
+    start: Instant,
+}
+
+impl Drop for RecordingGuard<'_> {
+    fn drop(&mut self) {
+        let end = Instant::now();
+        self.recorder.record(self.kind, self.label, self.thread_id, self.start, end);
+    }
+}
+
 #[must_use]
-pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>);
+pub struct TimingGuard<'a>(Option<measureme::TimingGuard<'a>>, Option<RecordingGuard<'a>>);
 
 impl<'a> TimingGuard<'a> {
     #[inline]
@@ -560,28 +1005,56 @@ pub fn start(
         profiler: &'a SelfProfiler,
         event_kind: StringId,
         event_id: EventId,
+    ) -> TimingGuard<'a> {
+        TimingGuard::start_recorded(
+            profiler,
+            event_kind,
+            event_id,
+            RecordedKind::Other,
+            RecordedLabel::None,
+        )
+    }
+
+    #[inline]
+    fn start_recorded(
+        profiler: &'a SelfProfiler,
+        event_kind: StringId,
+        event_id: EventId,
+        recorded_kind: RecordedKind,
+        recorded_label: RecordedLabel,
     ) -> TimingGuard<'a> {
         let thread_id = std::thread::current().id().as_u64().get() as u32;
         let raw_profiler = &profiler.profiler;
         let timing_guard =
             raw_profiler.start_recording_interval_event(event_kind, event_id, thread_id);
-        TimingGuard(Some(timing_guard))
+        let recording_guard = profiler.recorder.as_ref().map(|recorder| RecordingGuard {
+            recorder,
+            kind: recorded_kind,
+            label: recorded_label,
+            thread_id,
+            start: Instant::now(),
+        });
+        TimingGuard(Some(timing_guard), recording_guard)
     }
 
     #[inline]
     pub fn finish_with_query_invocation_id(self, query_invocation_id: QueryInvocationId) {
-        if let Some(guard) = self.0 {
+        let TimingGuard(guard, recording_guard) = self;
+        if let Some(guard) = guard {
             cold_path(|| {
                 let event_id = StringId::new_virtual(query_invocation_id.0);
                 let event_id = EventId::from_virtual(event_id);
                 guard.finish_with_override_event_id(event_id);
             });
         }
+        if let Some(mut recording_guard) = recording_guard {
+            recording_guard.label = RecordedLabel::QueryInvocation(query_invocation_id.0);
+        }
     }
 
     #[inline]
     pub fn none() -> TimingGuard<'a> {
-        TimingGuard(None)
+        TimingGuard(None, None)
     }
 
     #[inline(always)]
@@ -594,13 +1067,19 @@ pub fn run<R>(self, f: impl FnOnce() -> R) -> R {
 #[must_use]
 pub struct VerboseTimingGuard<'a> {
     start_and_message: Option<(Instant, Option<usize>, String)>,
+    format: TimePassesFormat,
     _guard: TimingGuard<'a>,
 }
 
 impl<'a> VerboseTimingGuard<'a> {
-    pub fn start(message: Option<String>, _guard: TimingGuard<'a>) -> Self {
+    pub fn start(
+        message: Option<String>,
+        format: TimePassesFormat,
+        _guard: TimingGuard<'a>,
+    ) -> Self {
         VerboseTimingGuard {
             _guard,
+            format,
             start_and_message: message.map(|msg| (Instant::now(), get_resident_set_size(), msg)),
         }
     }
@@ -616,7 +1095,13 @@ impl Drop for VerboseTimingGuard<'_> {
     fn drop(&mut self) {
         if let Some((start_time, start_rss, ref message)) = self.start_and_message {
             let end_rss = get_resident_set_size();
-            print_time_passes_entry(&message[..], start_time.elapsed(), start_rss, end_rss);
+            print_time_passes_entry(
+                &message[..],
+                start_time.elapsed(),
+                start_rss,
+                end_rss,
+                self.format,
+            );
         }
     }
 }
@@ -626,7 +1111,24 @@ pub fn print_time_passes_entry(
     dur: Duration,
     start_rss: Option<usize>,
     end_rss: Option<usize>,
+    format: TimePassesFormat,
 ) {
+    if format == TimePassesFormat::Json {
+        // RSS values are in bytes and `null` if they couldn't be determined.
+        let mut obj = json::Object::new();
+        obj.insert("pass".to_string(), what.to_json());
+        obj.insert("time".to_string(), dur.as_secs_f64().to_json());
+        obj.insert("rss_start".to_string(), start_rss.to_json());
+        obj.insert("rss_end".to_string(), end_rss.to_json());
+        let change_rss = match (start_rss, end_rss) {
+            (Some(start_rss), Some(end_rss)) => Some(end_rss as i64 - start_rss as i64),
+            _ => None,
+        };
+        obj.insert("rss_change".to_string(), change_rss.to_json());
+        eprintln!("{}", Json::Object(obj));
+        return;
+    }
+
     let rss_to_mb = |rss| (rss as f64 / 1_000_000.0).round() as usize;
     let rss_change_to_mb = |rss| (rss as f64 / 1_000_000.0).round() as i128;
 
@@ -72,7 +72,7 @@ fn annotation_type_for_level(level: Level) -> AnnotationType {
         Level::Help => AnnotationType::Help,
         // FIXME(#59346): Not sure how to map these two levels
         Level::Cancelled | Level::FailureNote => AnnotationType::Error,
-        Level::Allow => panic!("Should not call with Allow"),
+        Level::Allow | Level::Expect(_) => panic!("Should not call with Allow or Expect"),
     }
 }
 
@@ -138,7 +138,7 @@ fn emit_future_breakage_report(&mut self, diags: Vec<crate::Diagnostic>) {
         let data: Vec<FutureBreakageItem> = diags
             .into_iter()
             .map(|mut diag| {
-                if diag.level == crate::Level::Allow {
+                if matches!(diag.level, crate::Level::Allow | crate::Level::Expect(_)) {
                     diag.level = crate::Level::Warning;
                 }
                 FutureBreakageItem { diagnostic: Diagnostic::from_errors_diagnostic(&diag, self) }
@@ -412,6 +412,10 @@ struct HandlerInner {
 
     future_breakage_diagnostics: Vec<Diagnostic>,
 
+    /// The spans of the `#[expect]` lint attributes whose expectation was
+    /// fulfilled by a lint diagnostic at the `Expect` level.
+    fulfilled_expectations: FxHashSet<Span>,
+
     /// If set to `true`, no warning or error will be emitted.
     quiet: bool,
 }
@@ -528,6 +532,7 @@ pub fn with_emitter_and_flags(
                 emitted_diagnostics: Default::default(),
                 stashed_diagnostics: Default::default(),
                 future_breakage_diagnostics: Vec::new(),
+                fulfilled_expectations: Default::default(),
                 quiet: false,
             }),
         }
@@ -670,6 +675,12 @@ pub fn struct_allow(&self, msg: &str) -> DiagnosticBuilder<'_> {
         DiagnosticBuilder::new(self, Level::Allow, msg)
     }
 
+    /// Construct a builder at the `Expect` level with the `msg`. Emitting it
+    /// fulfills the `#[expect]` attribute identified by `expectation`.
+    pub fn struct_expect(&self, msg: &str, expectation: Span) -> DiagnosticBuilder<'_> {
+        DiagnosticBuilder::new(self, Level::Expect(expectation), msg)
+    }
+
     /// Construct a builder at the `Error` level at the given `span` and with the `msg`.
     pub fn struct_span_err(&self, span: impl Into<MultiSpan>, msg: &str) -> DiagnosticBuilder<'_> {
         let mut result = self.struct_err(msg);
@@ -840,6 +851,11 @@ pub fn take_future_breakage_diagnostics(&self) -> Vec<Diagnostic> {
         std::mem::take(&mut self.inner.borrow_mut().future_breakage_diagnostics)
     }
 
+    /// Returns the spans of all `#[expect]` attributes that have been fulfilled so far.
+    pub fn steal_fulfilled_expectations(&self) -> FxHashSet<Span> {
+        std::mem::take(&mut self.inner.borrow_mut().fulfilled_expectations)
+    }
+
     pub fn abort_if_errors(&self) {
         self.inner.borrow_mut().abort_if_errors()
     }
@@ -919,6 +935,11 @@ fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) {
 
         (*TRACK_DIAGNOSTICS)(diagnostic);
 
+        if let Expect(expectation) = diagnostic.level {
+            self.fulfilled_expectations.insert(expectation);
+            return;
+        }
+
         if diagnostic.level == Allow {
             return;
         }
@@ -1185,6 +1206,9 @@ pub enum Level {
     Cancelled,
     FailureNote,
     Allow,
+    /// A lint emitted under an `#[expect]` attribute. Such diagnostics are never
+    /// shown; they only mark the expectation at the given span as fulfilled.
+    Expect(Span),
 }
 
 impl fmt::Display for Level {
@@ -1210,7 +1234,7 @@ fn color(self) -> ColorSpec {
                 spec.set_fg(Some(Color::Cyan)).set_intense(true);
             }
             FailureNote => {}
-            Allow | Cancelled => unreachable!(),
+            Allow | Expect(_) | Cancelled => unreachable!(),
         }
         spec
     }
@@ -1225,6 +1249,7 @@ pub fn to_str(self) -> &'static str {
             FailureNote => "failure-note",
             Cancelled => panic!("Shouldn't call on cancelled error"),
             Allow => panic!("Shouldn't call on allowed error"),
+            Expect(_) => panic!("Shouldn't call on expected error"),
         }
     }
 
@@ -5,7 +5,7 @@
 use rustc_ast::tokenstream::{AttrAnnotatedTokenStream, AttrAnnotatedTokenTree};
 use rustc_ast::tokenstream::{DelimSpan, Spacing};
 use rustc_ast::tokenstream::{LazyTokenStream, TokenTree};
-use rustc_ast::{self as ast, AstLike, AttrItem, AttrStyle, Attribute, MetaItem};
+use rustc_ast::{self as ast, AstLike, AttrItem, AttrStyle, Attribute, MetaItem, NodeId};
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::map_in_place::MapInPlace;
@@ -29,6 +29,8 @@ pub struct StripUnconfigured<'a> {
     /// This is only used for the input to derive macros,
     /// which needs eager expansion of `cfg` and `cfg_attr`
     pub config_tokens: bool,
+    /// The node that `unexpected_cfgs` lints are attached to.
+    pub lint_node_id: NodeId,
 }
 
 fn get_features(
@@ -199,7 +201,12 @@ fn active_features_up_to(edition: Edition) -> impl Iterator<Item = &'static Feat
 
 // `cfg_attr`-process the crate's attributes and compute the crate's features.
 pub fn features(sess: &Session, mut krate: ast::Crate) -> (ast::Crate, Features) {
-    let mut strip_unconfigured = StripUnconfigured { sess, features: None, config_tokens: false };
+    let mut strip_unconfigured = StripUnconfigured {
+        sess,
+        features: None,
+        config_tokens: false,
+        lint_node_id: ast::CRATE_NODE_ID,
+    };
 
     let unconfigured_attrs = krate.attrs.clone();
     let diag = &sess.parse_sess.span_diagnostic;
@@ -237,6 +244,16 @@ macro_rules! configure {
     };
 }
 
+#[macro_export]
+macro_rules! configure_item {
+    ($this:ident, $node:ident) => {
+        match $this.configure_item($node) {
+            Some(node) => node,
+            None => return Default::default(),
+        }
+    };
+}
+
 const CFG_ATTR_GRAMMAR_HELP: &str = "#[cfg_attr(condition, attribute, other_attribute, ...)]";
 const CFG_ATTR_NOTE_REF: &str = "for more information, visit \
     <https://doc.rust-lang.org/reference/conditional-compilation.html\
@@ -245,7 +262,19 @@ macro_rules! configure {
 impl<'a> StripUnconfigured<'a> {
     pub fn configure<T: AstLike>(&mut self, mut node: T) -> Option<T> {
         self.process_cfg_attrs(&mut node);
-        if self.in_cfg(node.attrs()) {
+        if self.in_cfg(node.attrs(), false) {
+            self.try_configure_tokens(&mut node);
+            Some(node)
+        } else {
+            None
+        }
+    }
+
+    /// Like `configure`, but leaves `#[cfg]` attributes using `accessible(path)` predicates
+    /// in place, so they can be expanded once the paths can be resolved.
+    pub fn configure_item<T: AstLike>(&mut self, mut node: T) -> Option<T> {
+        self.process_cfg_attrs(&mut node);
+        if self.in_cfg(node.attrs(), true) {
             self.try_configure_tokens(&mut node);
             Some(node)
         } else {
@@ -267,7 +296,7 @@ fn configure_krate_attrs(
         mut attrs: Vec<ast::Attribute>,
     ) -> Option<Vec<ast::Attribute>> {
         attrs.flat_map_in_place(|attr| self.process_cfg_attr(attr));
-        if self.in_cfg(&attrs) { Some(attrs) } else { None }
+        if self.in_cfg(&attrs, false) { Some(attrs) } else { None }
     }
 
     /// Performs cfg-expansion on `stream`, producing a new `AttrAnnotatedTokenStream`.
@@ -296,7 +325,7 @@ fn can_skip(stream: &AttrAnnotatedTokenStream) -> bool {
                     attrs.flat_map_in_place(|attr| self.process_cfg_attr(attr));
                     data.attrs = attrs.into();
 
-                    if self.in_cfg(&data.attrs) {
+                    if self.in_cfg(&data.attrs, false) {
                         data.tokens = LazyTokenStream::new(
                             self.configure_tokens(&data.tokens.create_token_stream()),
                         );
@@ -362,7 +391,12 @@ fn process_cfg_attr(&mut self, attr: Attribute) -> Vec<Attribute> {
         // At this point we know the attribute is considered used.
         self.sess.mark_attr_used(&attr);
 
-        if !attr::cfg_matches(&cfg_predicate, &self.sess.parse_sess, self.features) {
+        if !attr::cfg_matches(
+            &cfg_predicate,
+            &self.sess.parse_sess,
+            self.lint_node_id,
+            self.features,
+        ) {
             return vec![];
         }
 
@@ -415,6 +449,16 @@ fn process_cfg_attr(&mut self, attr: Attribute) -> Vec<Attribute> {
             .collect()
     }
 
+    /// `accessible(path)` predicates cannot be evaluated before name resolution, so an outer
+    /// `#[cfg]` attribute on an item using one is expanded like an attribute macro instead,
+    /// which keeps or removes the item once the paths can be resolved.
+    pub fn is_deferred_cfg(&self, attr: &Attribute) -> bool {
+        attr.style == AttrStyle::Outer
+            && self.features.map_or(false, |features| features.cfg_accessible)
+            && attr.has_name(sym::cfg)
+            && attr.meta_item_list().map_or(false, |list| list.iter().any(has_accessible_predicate))
+    }
+
     fn parse_cfg_attr(&self, attr: &Attribute) -> Option<(MetaItem, Vec<(AttrItem, Span)>)> {
         match attr.get_normal_item().args {
             ast::MacArgs::Delimited(dspan, delim, ref tts) if !tts.is_empty() => {
@@ -452,9 +496,10 @@ fn error_malformed_cfg_attr_missing(&self, span: Span) {
     }
 
     /// Determines if a node with the given attributes should be included in this configuration.
-    fn in_cfg(&self, attrs: &[Attribute]) -> bool {
+    /// Deferred `#[cfg]` attributes are skipped if `defer_accessible` is set.
+    fn in_cfg(&self, attrs: &[Attribute], defer_accessible: bool) -> bool {
         attrs.iter().all(|attr| {
-            if !is_cfg(self.sess, attr) {
+            if !is_cfg(self.sess, attr) || defer_accessible && self.is_deferred_cfg(attr) {
                 return true;
             }
             let meta_item = match validate_attr::parse_meta(&self.sess.parse_sess, attr) {
@@ -465,7 +510,12 @@ fn in_cfg(&self, attrs: &[Attribute]) -> bool {
                 }
             };
             parse_cfg(&meta_item, &self.sess).map_or(true, |meta_item| {
-                attr::cfg_matches(&meta_item, &self.sess.parse_sess, self.features)
+                attr::cfg_matches(
+                    &meta_item,
+                    &self.sess.parse_sess,
+                    self.lint_node_id,
+                    self.features,
+                )
             })
         })
     }
@@ -506,6 +556,8 @@ pub fn configure_expr(&mut self, expr: &mut P<ast::Expr>) {
         }
 
         self.process_cfg_attrs(expr);
+        // Only items can be removed by deferred `#[cfg]` attributes.
+        expr.visit_attrs(|attrs| attrs.retain(|attr| !self.is_deferred_cfg(attr)));
         self.try_configure_tokens(&mut *expr);
     }
 }
@@ -536,6 +588,19 @@ pub fn parse_cfg<'a>(meta_item: &'a MetaItem, sess: &Session) -> Option<&'a Meta
     }
 }
 
+fn has_accessible_predicate(nested: &ast::NestedMetaItem) -> bool {
+    match nested.meta_item() {
+        Some(mi) => match (mi.name_or_empty(), mi.meta_item_list()) {
+            (sym::accessible, Some(_)) => true,
+            (sym::any | sym::all | sym::not, Some(list)) => {
+                list.iter().any(has_accessible_predicate)
+            }
+            _ => false,
+        },
+        None => false,
+    }
+}
+
 fn is_cfg(sess: &Session, attr: &Attribute) -> bool {
     sess.check_name(attr, sym::cfg)
 }
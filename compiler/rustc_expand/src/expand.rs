@@ -1,10 +1,10 @@
 use crate::base::*;
 use crate::config::StripUnconfigured;
-use crate::configure;
 use crate::hygiene::SyntaxContext;
 use crate::mbe::macro_rules::annotate_err_with_kind;
 use crate::module::{mod_dir_path, parse_external_mod, DirOwnership, ParsedExternalMod};
 use crate::placeholders::{placeholder, PlaceholderExpander};
+use crate::{configure, configure_item};
 
 use rustc_ast as ast;
 use rustc_ast::mut_visit::*;
@@ -590,16 +590,6 @@ fn collect_invocations(
 
         let invocations = {
             let mut collector = InvocationCollector {
-                // Non-derive macro invocations cannot see the results of cfg expansion - they
-                // will either be removed along with the item, or invoked before the cfg/cfg_attr
-                // attribute is expanded. Therefore, we don't need to configure the tokens
-                // Derive macros *can* see the results of cfg-expansion - they are handled
-                // specially in `fully_expand_fragment`
-                cfg: StripUnconfigured {
-                    sess: &self.cx.sess,
-                    features: self.cx.ecfg.features,
-                    config_tokens: false,
-                },
                 cx: self.cx,
                 invocations: Vec::new(),
                 monotonic: self.monotonic,
@@ -986,7 +976,6 @@ pub fn ensure_complete_parse<'a>(
 
 struct InvocationCollector<'a, 'b> {
     cx: &'a mut ExtCtxt<'b>,
-    cfg: StripUnconfigured<'a>,
     invocations: Vec<(Invocation, Option<Lrc<SyntaxExtension>>)>,
     monotonic: bool,
 }
@@ -1040,7 +1029,10 @@ fn take_first_attr(
         item.visit_attrs(|attrs| {
             attr = attrs
                 .iter()
-                .position(|a| !self.cx.expanded_inert_attrs.is_marked(a) && !is_builtin_attr(a))
+                .position(|a| {
+                    !self.cx.expanded_inert_attrs.is_marked(a)
+                        && (!is_builtin_attr(a) || self.cfg().is_deferred_cfg(a))
+                })
                 .map(|attr_pos| {
                     let attr = attrs.remove(attr_pos);
                     let following_derives = attrs[attr_pos..]
@@ -1064,8 +1056,28 @@ fn take_first_attr(
         attr
     }
 
+    fn cfg(&self) -> StripUnconfigured<'_> {
+        StripUnconfigured {
+            sess: &self.cx.sess,
+            features: self.cx.ecfg.features,
+            // Non-derive macro invocations cannot see the results of cfg expansion - they
+            // will either be removed along with the item, or invoked before the cfg/cfg_attr
+            // attribute is expanded. Therefore, we don't need to configure the tokens
+            // Derive macros *can* see the results of cfg-expansion - they are handled
+            // specially in `fully_expand_fragment`
+            config_tokens: false,
+            // The node being configured has no `NodeId` yet, and it may be removed
+            // entirely, so lints are attached to the closest enclosing node instead.
+            lint_node_id: self.cx.current_expansion.lint_node_id,
+        }
+    }
+
     fn configure<T: AstLike>(&mut self, node: T) -> Option<T> {
-        self.cfg.configure(node)
+        self.cfg().configure(node)
+    }
+
+    fn configure_item<T: AstLike>(&mut self, node: T) -> Option<T> {
+        self.cfg().configure_item(node)
     }
 
     // Detect use of feature-gated or invalid attributes on macro invocations
@@ -1154,12 +1166,12 @@ macro_rules! assign_id {
 
 impl<'a, 'b> MutVisitor for InvocationCollector<'a, 'b> {
     fn visit_expr(&mut self, expr: &mut P<ast::Expr>) {
-        self.cfg.configure_expr(expr);
+        self.cfg().configure_expr(expr);
         visit_clobber(expr.deref_mut(), |mut expr| {
             if let Some(attr) = self.take_first_attr(&mut expr) {
                 // Collect the invoc regardless of whether or not attributes are permitted here
                 // expansion will eat the attribute so it won't error later.
-                self.cfg.maybe_emit_expr_attr_err(&attr.0);
+                self.cfg().maybe_emit_expr_attr_err(&attr.0);
 
                 // AstFragmentKind::Expr requires the macro to emit an expression.
                 return self
@@ -1261,7 +1273,7 @@ fn filter_map_expr(&mut self, expr: P<ast::Expr>) -> Option<P<ast::Expr>> {
         let expr = configure!(self, expr);
         expr.filter_map(|mut expr| {
             if let Some(attr) = self.take_first_attr(&mut expr) {
-                self.cfg.maybe_emit_expr_attr_err(&attr.0);
+                self.cfg().maybe_emit_expr_attr_err(&attr.0);
 
                 return self
                     .collect_attr(attr, Annotatable::Expr(P(expr)), AstFragmentKind::OptExpr)
@@ -1300,7 +1312,10 @@ fn visit_pat(&mut self, pat: &mut P<ast::Pat>) {
     }
 
     fn flat_map_stmt(&mut self, stmt: ast::Stmt) -> SmallVec<[ast::Stmt; 1]> {
-        let mut stmt = configure!(self, stmt);
+        let mut stmt = match stmt.kind {
+            StmtKind::Item(..) => configure_item!(self, stmt),
+            _ => configure!(self, stmt),
+        };
 
         // we'll expand attributes on expressions separately
         if !stmt.is_expr() {
@@ -1364,7 +1379,7 @@ fn visit_block(&mut self, block: &mut P<Block>) {
     }
 
     fn flat_map_item(&mut self, item: P<ast::Item>) -> SmallVec<[P<ast::Item>; 1]> {
-        let mut item = configure!(self, item);
+        let mut item = configure_item!(self, item);
 
         if let Some(attr) = self.take_first_attr(&mut item) {
             return self
@@ -1430,7 +1445,7 @@ fn visit_block(&mut self, block: &mut P<Block>) {
                             // If we loaded an out-of-line module and added some inner attributes,
                             // then we need to re-configure it and re-collect attributes for
                             // resolution and expansion.
-                            item = configure!(self, item);
+                            item = configure_item!(self, item);
 
                             if let Some(attr) = self.take_first_attr(&mut item) {
                                 return self
@@ -1479,7 +1494,7 @@ fn visit_block(&mut self, block: &mut P<Block>) {
     }
 
     fn flat_map_trait_item(&mut self, item: P<ast::AssocItem>) -> SmallVec<[P<ast::AssocItem>; 1]> {
-        let mut item = configure!(self, item);
+        let mut item = configure_item!(self, item);
 
         if let Some(attr) = self.take_first_attr(&mut item) {
             return self
@@ -1504,7 +1519,7 @@ fn visit_block(&mut self, block: &mut P<Block>) {
     }
 
     fn flat_map_impl_item(&mut self, item: P<ast::AssocItem>) -> SmallVec<[P<ast::AssocItem>; 1]> {
-        let mut item = configure!(self, item);
+        let mut item = configure_item!(self, item);
 
         if let Some(attr) = self.take_first_attr(&mut item) {
             return self
@@ -1546,7 +1561,7 @@ fn flat_map_foreign_item(
         &mut self,
         foreign_item: P<ast::ForeignItem>,
     ) -> SmallVec<[P<ast::ForeignItem>; 1]> {
-        let mut foreign_item = configure!(self, foreign_item);
+        let mut foreign_item = configure_item!(self, foreign_item);
 
         if let Some(attr) = self.take_first_attr(&mut foreign_item) {
             return self
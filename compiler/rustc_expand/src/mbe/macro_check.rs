@@ -104,7 +104,7 @@
 //! Kleene operators under which a meta-variable is repeating is the concatenation of the stacks
 //! stored when entering a macro definition starting from the state in which the meta-variable is
 //! bound.
-use crate::mbe::{KleeneToken, TokenTree};
+use crate::mbe::{KleeneToken, MetaVarExpr, TokenTree};
 
 use rustc_ast::token::{DelimToken, Token, TokenKind};
 use rustc_ast::{NodeId, DUMMY_NODE_ID};
@@ -278,6 +278,8 @@ fn check_binders(
                 binders.insert(name, BinderInfo { span, ops: ops.into() });
             }
         }
+        // `MetaVarExpr` can not appear in the LHS of a macro arm
+        TokenTree::MetaVarExpr(..) => {}
         TokenTree::Delimited(_, ref del) => {
             for tt in &del.tts {
                 check_binders(sess, node_id, tt, macros, binders, ops, valid);
@@ -335,6 +337,21 @@ fn check_occurrences(
             let name = MacroRulesNormalizedIdent::new(name);
             check_ops_is_prefix(sess, node_id, macros, binders, ops, span, name);
         }
+        TokenTree::MetaVarExpr(dl, ref mve) => match *mve {
+            // `count` may occur at a lower depth than the meta-variable it counts.
+            MetaVarExpr::Count(ident, _) => {
+                let name = MacroRulesNormalizedIdent::new(ident);
+                if get_binder_info(macros, binders, name).is_none() {
+                    let msg = format!("unknown macro variable `{}`", name);
+                    buffer_lint(sess, dl.entire().into(), node_id, &msg);
+                }
+            }
+            MetaVarExpr::Ignore(ident) => {
+                let name = MacroRulesNormalizedIdent::new(ident);
+                check_ops_is_prefix(sess, node_id, macros, binders, ops, dl.entire(), name);
+            }
+            MetaVarExpr::Index(..) | MetaVarExpr::Len(..) => {}
+        },
         TokenTree::Delimited(_, ref del) => {
             check_nested_occurrences(sess, node_id, &del.tts, macros, binders, ops, valid);
         }
@@ -584,7 +584,10 @@ fn check_lhs_no_empty_seq(sess: &ParseSess, tts: &[mbe::TokenTree]) -> bool {
     use mbe::TokenTree;
     for tt in tts {
         match *tt {
-            TokenTree::Token(..) | TokenTree::MetaVar(..) | TokenTree::MetaVarDecl(..) => (),
+            TokenTree::Token(..)
+            | TokenTree::MetaVar(..)
+            | TokenTree::MetaVarDecl(..)
+            | TokenTree::MetaVarExpr(..) => (),
             TokenTree::Delimited(_, ref del) => {
                 if !check_lhs_no_empty_seq(sess, &del.tts) {
                     return false;
@@ -676,6 +679,7 @@ fn build_recur(sets: &mut FirstSets, tts: &[TokenTree]) -> TokenSet {
                     TokenTree::Token(..) | TokenTree::MetaVar(..) | TokenTree::MetaVarDecl(..) => {
                         first.replace_with(tt.clone());
                     }
+                    TokenTree::MetaVarExpr(..) => {}
                     TokenTree::Delimited(span, ref delimited) => {
                         build_recur(sets, &delimited.tts[..]);
                         first.replace_with(delimited.open_tt(span));
@@ -739,6 +743,7 @@ fn first(&self, tts: &[mbe::TokenTree]) -> TokenSet {
                     first.add_one(tt.clone());
                     return first;
                 }
+                TokenTree::MetaVarExpr(..) => {}
                 TokenTree::Delimited(span, ref delimited) => {
                     first.add_one(delimited.open_tt(span));
                     return first;
@@ -969,6 +974,7 @@ fn check_matcher_core(
                 // check below, so we can just keep going forward here.
                 continue 'each_token;
             }
+            TokenTree::MetaVarExpr(..) => continue 'each_token,
         }
 
         // (`suffix_first` guaranteed initialized once reaching here.)
@@ -1,13 +1,13 @@
 use crate::mbe::macro_parser;
-use crate::mbe::{Delimited, KleeneOp, KleeneToken, SequenceRepetition, TokenTree};
+use crate::mbe::{Delimited, KleeneOp, KleeneToken, MetaVarExpr, SequenceRepetition, TokenTree};
 
 use rustc_ast::token::{self, Token};
 use rustc_ast::tokenstream;
 use rustc_ast::{NodeId, DUMMY_NODE_ID};
 use rustc_ast_pretty::pprust;
 use rustc_feature::Features;
-use rustc_session::parse::ParseSess;
-use rustc_span::symbol::{kw, Ident};
+use rustc_session::parse::{feature_err, ParseSess};
+use rustc_span::symbol::{kw, sym, Ident};
 
 use rustc_span::edition::Edition;
 use rustc_span::{Span, SyntaxContext};
@@ -161,6 +161,22 @@ fn parse_tree(
                 // `tree` is followed by a delimited set of token trees. This indicates the beginning
                 // of a repetition sequence in the macro (e.g. `$(pat)*`).
                 Some(tokenstream::TokenTree::Delimited(span, delim, tts)) => {
+                    // `${...}` in the RHS is a meta-variable expression, e.g. `${count(x)}`.
+                    if delim == token::Brace && !expect_matchers {
+                        if !features.macro_metavar_expr {
+                            let msg = "meta-variable expressions are unstable";
+                            feature_err(sess, sym::macro_metavar_expr, span.entire(), msg).emit();
+                            return TokenTree::token(token::Dollar, span.entire());
+                        }
+                        return match MetaVarExpr::parse(&tts, span.entire(), sess) {
+                            Ok(elem) => TokenTree::MetaVarExpr(span, elem),
+                            Err(mut err) => {
+                                err.emit();
+                                TokenTree::token(token::Dollar, span.entire())
+                            }
+                        };
+                    }
+
                     // Must have `(` not `{` or `[`
                     if delim != token::Paren {
                         let tok = pprust::token_kind_to_string(&token::OpenDelim(delim));
@@ -19,8 +19,8 @@
 use rustc_span::{BytePos, FileName, MultiSpan, Pos, RealFileName, SourceFile, Span};
 
 use pm::bridge::{server, TokenTree};
-use pm::{Delimiter, Level, LineColumn, Spacing};
-use std::ops::Bound;
+use pm::{Applicability, Delimiter, Level, LineColumn, Spacing};
+use std::ops::{Bound, Range};
 use std::{ascii, panic};
 
 trait FromInternal<T> {
@@ -281,6 +281,18 @@ fn to_internal(self) -> rustc_errors::Level {
     }
 }
 
+impl ToInternal<rustc_errors::Applicability> for Applicability {
+    fn to_internal(self) -> rustc_errors::Applicability {
+        match self {
+            Applicability::MachineApplicable => rustc_errors::Applicability::MachineApplicable,
+            Applicability::MaybeIncorrect => rustc_errors::Applicability::MaybeIncorrect,
+            Applicability::HasPlaceholders => rustc_errors::Applicability::HasPlaceholders,
+            Applicability::Unspecified => rustc_errors::Applicability::Unspecified,
+            _ => unreachable!("unknown proc_macro::Applicability variant: {:?}", self),
+        }
+    }
+}
+
 pub struct FreeFunctions;
 
 #[derive(Clone)]
@@ -718,6 +730,16 @@ fn sub(
     ) {
         diag.sub(level.to_internal(), msg, MultiSpan::from_spans(spans), None);
     }
+    fn suggestion(
+        &mut self,
+        diag: &mut Self::Diagnostic,
+        msg: &str,
+        span: Self::Span,
+        replacement: &str,
+        applicability: Applicability,
+    ) {
+        diag.span_suggestion(span, msg, replacement.to_string(), applicability.to_internal());
+    }
     fn emit(&mut self, diag: Self::Diagnostic) {
         self.sess.span_diagnostic.emit_diagnostic(&diag);
     }
@@ -757,6 +779,12 @@ fn end(&mut self, span: Self::Span) -> LineColumn {
         let loc = self.sess.source_map().lookup_char_pos(span.hi());
         LineColumn { line: loc.line, column: loc.col.to_usize() }
     }
+    fn byte_range(&mut self, span: Self::Span) -> Range<usize> {
+        let source_map = self.sess.source_map();
+        let relative_start_pos = source_map.lookup_byte_offset(span.lo()).pos;
+        let relative_end_pos = source_map.lookup_byte_offset(span.hi()).pos;
+        Range { start: relative_start_pos.0 as usize, end: relative_end_pos.0 as usize }
+    }
     fn join(&mut self, first: Self::Span, second: Self::Span) -> Option<Self::Span> {
         let self_loc = self.sess.source_map().lookup_char_pos(first.lo());
         let other_loc = self.sess.source_map().lookup_char_pos(second.lo());
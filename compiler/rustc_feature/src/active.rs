@@ -682,6 +682,12 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows using doc(primitive) without a future-incompat warning
     (active, doc_primitive, "1.56.0", Some(88070), None),
 
+    /// Allows macro_rules! metavariable expressions like `${count(x)}` and `${index()}`.
+    (active, macro_metavar_expr, "1.56.0", Some(83527), None),
+
+    /// Allows the `fuzzy_provenance_casts` and `lossy_provenance_casts` lints.
+    (active, strict_provenance, "1.56.0", Some(95228), None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
@@ -35,6 +35,7 @@ macro_rules! cfg_fn {
     (sym::sanitize, sym::cfg_sanitize, cfg_fn!(cfg_sanitize)),
     (sym::version, sym::cfg_version, cfg_fn!(cfg_version)),
     (sym::panic, sym::cfg_panic, cfg_fn!(cfg_panic)),
+    (sym::accessible, sym::cfg_accessible, cfg_fn!(cfg_accessible)),
 ];
 
 /// Find a gated cfg determined by the `pred`icate which is given the cfg's name.
@@ -206,6 +207,10 @@ macro_rules! experimental {
     ungated!(allow, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#)),
     ungated!(forbid, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#)),
     ungated!(deny, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#)),
+    gated!(
+        expect, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#),
+        lint_reasons, experimental!(expect)
+    ),
     ungated!(must_use, AssumedUsed, template!(Word, NameValueStr: "reason")),
     // FIXME(#14407)
     ungated!(
@@ -458,6 +463,9 @@ macro_rules! experimental {
     ),
     // Enumerates "identity-like" conversion methods to suggest on type mismatch.
     rustc_attr!(rustc_conversion_suggestion, AssumedUsed, template!(Word), INTERNAL_UNSTABLE),
+    // Marks traits whose derived impls only perform "trivial" field reads, which
+    // the `fields_only_read_by_derives` lint doesn't count as reads.
+    rustc_attr!(rustc_trivial_field_reads, AssumedUsed, template!(Word), INTERNAL_UNSTABLE),
 
     // ==========================================================================
     // Internal attributes, Const related:
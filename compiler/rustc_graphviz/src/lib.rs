@@ -444,6 +444,14 @@ fn node_shape(&'a self, _node: &Self::Node) -> Option<LabelText<'a>> {
         None
     }
 
+    /// Maps `n` to one of the [graphviz `color` names][1]. If `None`
+    /// is returned, no `color` attribute is specified.
+    ///
+    /// [1]: https://www.graphviz.org/doc/info/colors.html
+    fn node_color(&'a self, _node: &Self::Node) -> Option<LabelText<'a>> {
+        None
+    }
+
     /// Maps `n` to a label that will be used in the rendered output.
     /// The label need not be unique, and may be the empty string; the
     /// default is just the output from `node_id`.
@@ -658,6 +666,10 @@ pub fn render_opts<'a, N, E, G, W>(g: &'a G, w: &mut W, options: &[RenderOption]
             write!(text, "[shape={}]", &s.to_dot_string()).unwrap();
         }
 
+        if let Some(c) = g.node_color(n) {
+            write!(text, "[color={}]", &c.to_dot_string()).unwrap();
+        }
+
         writeln!(text, ";").unwrap();
         w.write_all(&text[..])?;
 
@@ -34,17 +34,18 @@
 //! ```
 
 use rustc_ast as ast;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::graph::implementation::{Direction, NodeIndex, INCOMING, OUTGOING};
 use rustc_graphviz as dot;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc_middle::dep_graph::{
-    DepGraphQuery, DepKind, DepNode, DepNodeExt, DepNodeFilter, EdgeFilter,
+    DepGraphQuery, DepKind, DepNode, DepNodeColor, DepNodeExt, DepNodeFilter, EdgeFilter,
 };
 use rustc_middle::hir::map::Map;
 use rustc_middle::ty::TyCtxt;
+use rustc_serialize::json::{self, Json};
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::Span;
 
@@ -55,7 +56,7 @@
 pub fn assert_dep_graph(tcx: TyCtxt<'_>) {
     tcx.dep_graph.with_ignore(|| {
         if tcx.sess.opts.debugging_opts.dump_dep_graph {
-            tcx.dep_graph.with_query(dump_graph);
+            tcx.dep_graph.with_query(|query| dump_graph(tcx, query));
         }
 
         if !tcx.sess.opts.debugging_opts.query_dep_graph {
@@ -222,10 +223,10 @@ fn check_paths<'tcx>(tcx: TyCtxt<'tcx>, if_this_changed: &Sources, then_this_wou
     });
 }
 
-fn dump_graph(query: &DepGraphQuery) {
+fn dump_graph(tcx: TyCtxt<'_>, query: &DepGraphQuery) {
     let path: String = env::var("RUST_DEP_GRAPH").unwrap_or_else(|_| "dep_graph".to_string());
 
-    let nodes = match env::var("RUST_DEP_GRAPH_FILTER") {
+    let mut nodes = match env::var("RUST_DEP_GRAPH_FILTER") {
         Ok(string) => {
             // Expect one of: "-> target", "source -> target", or "source ->".
             let edge_filter =
@@ -236,7 +237,24 @@ fn dump_graph(query: &DepGraphQuery) {
         }
         Err(_) => query.nodes().into_iter().collect(),
     };
-    let edges = filter_edges(&query, &nodes);
+    let mut edges = filter_edges(&query, &nodes);
+
+    if let Some(kinds) = &tcx.sess.opts.debugging_opts.dep_graph_filter {
+        let kinds: FxHashSet<&str> = kinds.split(',').map(|kind| kind.trim()).collect();
+        for kind in &kinds {
+            if !DepNode::has_label_string(kind) {
+                tcx.sess
+                    .warn(&format!("unknown dep-node kind `{}` in `-Z dep-graph-filter`", kind));
+            }
+        }
+        let kept: FxHashSet<&DepNode> =
+            nodes.iter().copied().filter(|n| kinds.contains(&*format!("{:?}", n.kind))).collect();
+        edges = contract_edges(&query, &nodes, &kept);
+        nodes = kept;
+    }
+
+    let colors: FxHashMap<&DepNode, &'static str> =
+        nodes.iter().map(|&n| (n, node_color(tcx, n))).collect();
 
     {
         // dump a .txt file with just the edges:
@@ -247,16 +265,63 @@ fn dump_graph(query: &DepGraphQuery) {
         }
     }
 
+    {
+        // dump a .json file with the nodes, their colors and the edges:
+        let mut json_nodes: Vec<_> =
+            colors.iter().map(|(node, &color)| (format!("{:?}", node), color)).collect();
+        json_nodes.sort();
+        let json_nodes = json_nodes
+            .into_iter()
+            .map(|(node, color)| {
+                let mut obj = json::Object::new();
+                obj.insert("node".to_string(), Json::String(node));
+                obj.insert("color".to_string(), Json::String(color.to_string()));
+                Json::Object(obj)
+            })
+            .collect();
+        let json_edges = edges
+            .iter()
+            .map(|(source, target)| {
+                let mut obj = json::Object::new();
+                obj.insert("source".to_string(), Json::String(format!("{:?}", source)));
+                obj.insert("target".to_string(), Json::String(format!("{:?}", target)));
+                Json::Object(obj)
+            })
+            .collect();
+        let mut graph = json::Object::new();
+        graph.insert("nodes".to_string(), Json::Array(json_nodes));
+        graph.insert("edges".to_string(), Json::Array(json_edges));
+        let json_path = format!("{}.json", path);
+        fs::write(json_path, Json::Object(graph).to_string()).unwrap();
+    }
+
     {
         // dump a .dot file in graphviz format:
         let dot_path = format!("{}.dot", path);
         let mut v = Vec::new();
-        dot::render(&GraphvizDepGraph(nodes, edges), &mut v).unwrap();
+        dot::render(&GraphvizDepGraph(nodes, edges, colors), &mut v).unwrap();
         fs::write(dot_path, v).unwrap();
     }
 }
 
-pub struct GraphvizDepGraph<'q>(FxHashSet<&'q DepNode>, Vec<(&'q DepNode, &'q DepNode)>);
+/// Classifies `node` by the result of trying to mark it green: `"green"` and `"red"` nodes
+/// existed in the previous session, `"new"` nodes did not.
+fn node_color(tcx: TyCtxt<'_>, node: &DepNode) -> &'static str {
+    if tcx.dep_graph.prev_fingerprint_of(node).is_none() {
+        return "new";
+    }
+    match tcx.dep_graph.node_color(node) {
+        Some(DepNodeColor::Green(_)) => "green",
+        Some(DepNodeColor::Red) => "red",
+        None => "unknown",
+    }
+}
+
+pub struct GraphvizDepGraph<'q>(
+    FxHashSet<&'q DepNode>,
+    Vec<(&'q DepNode, &'q DepNode)>,
+    FxHashMap<&'q DepNode, &'static str>,
+);
 
 impl<'a, 'q> dot::GraphWalk<'a> for GraphvizDepGraph<'q> {
     type Node = &'q DepNode;
@@ -293,6 +358,14 @@ fn node_id(&self, n: &&'q DepNode) -> dot::Id<'_> {
     fn node_label(&self, n: &&'q DepNode) -> dot::LabelText<'_> {
         dot::LabelText::label(format!("{:?}", n))
     }
+    fn node_color(&self, n: &&'q DepNode) -> Option<dot::LabelText<'_>> {
+        match self.2[n] {
+            "green" => Some(dot::LabelText::label("green")),
+            "red" => Some(dot::LabelText::label("red")),
+            "new" => Some(dot::LabelText::label("blue")),
+            _ => None,
+        }
+    }
 }
 
 // Given an optional filter like `"x,y,z"`, returns either `None` (no
@@ -433,3 +506,32 @@ fn filter_edges<'q>(
         .filter(|&(source, target)| nodes.contains(source) && nodes.contains(target))
         .collect()
 }
+
+/// Computes the edges between the `kept` nodes, where an edge stands for a path through nodes
+/// of `nodes` that were filtered out.
+fn contract_edges<'q>(
+    query: &'q DepGraphQuery,
+    nodes: &FxHashSet<&'q DepNode>,
+    kept: &FxHashSet<&'q DepNode>,
+) -> Vec<(&'q DepNode, &'q DepNode)> {
+    let mut edges = Vec::new();
+    for &source in kept {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![query.indices[source]];
+        while let Some(index) = stack.pop() {
+            for (_, edge) in query.graph.adjacent_edges(index, OUTGOING) {
+                let neighbor_index = edge.target();
+                let neighbor = query.graph.node_data(neighbor_index);
+                if !nodes.contains(neighbor) || !visited.insert(neighbor) {
+                    continue;
+                }
+                if kept.contains(neighbor) {
+                    edges.push((source, neighbor));
+                } else {
+                    stack.push(neighbor_index);
+                }
+            }
+        }
+    }
+    edges
+}
@@ -13,12 +13,14 @@
 use rustc_middle::ty;
 use rustc_parse::new_parser_from_source_str;
 use rustc_query_impl::QueryCtxt;
-use rustc_session::config::{self, ErrorOutputType, Input, OutputFilenames};
+use rustc_session::config::{self, CheckCfg, ErrorOutputType, ExpectedValues};
+use rustc_session::config::{Input, OutputFilenames};
 use rustc_session::early_error;
 use rustc_session::lint;
 use rustc_session::parse::{CrateConfig, ParseSess};
 use rustc_session::{DiagnosticOutput, Session};
 use rustc_span::source_map::{FileLoader, FileName};
+use rustc_span::symbol::sym;
 use std::path::PathBuf;
 use std::result;
 use std::sync::{Arc, Mutex};
@@ -123,6 +125,105 @@ macro_rules! error {
     })
 }
 
+/// Converts strings provided as `--check-cfg [specs]` into a `CheckCfg`.
+pub fn parse_check_cfg(specs: Vec<String>) -> CheckCfg {
+    rustc_span::create_default_session_if_not_set_then(move |_| {
+        let mut check_cfg = CheckCfg::default();
+
+        for s in specs {
+            check_cfg.exhaustive_names = true;
+
+            let sess = ParseSess::with_silent_emitter();
+            let filename = FileName::cfg_spec_source_code(&s);
+            let mut parser = new_parser_from_source_str(&sess, filename, s.to_string());
+
+            macro_rules! error {
+                ($reason: expr) => {
+                    early_error(
+                        ErrorOutputType::default(),
+                        &format!(
+                            concat!("invalid `--check-cfg` argument: `{}` (", $reason, ")"),
+                            s
+                        ),
+                    )
+                };
+            }
+
+            let meta_item = match parser.parse_meta_item() {
+                Ok(meta_item) if parser.token == token::Eof => meta_item,
+                Ok(..) => error!(r#"expected `cfg(name, values("value"))`"#),
+                Err(err) => {
+                    err.cancel();
+                    error!(r#"expected `cfg(name, values("value"))`"#);
+                }
+            };
+            let args = match meta_item.meta_item_list() {
+                Some(args) if meta_item.has_name(sym::cfg) => args,
+                _ => error!(r#"expected `cfg(name, values("value"))`"#),
+            };
+
+            let mut names = Vec::new();
+            let mut values: Option<ExpectedValues<String>> = None;
+            for arg in args {
+                if arg.is_word() {
+                    match arg.ident() {
+                        Some(ident) => names.push(ident.name.to_string()),
+                        None => error!("`cfg()` names must be simple identifiers"),
+                    }
+                } else if arg.has_name(sym::values) {
+                    if values.is_some() {
+                        error!("`values()` cannot be specified multiple times");
+                    }
+                    let mut expected = FxHashSet::default();
+                    let mut any = false;
+                    for value in arg.meta_item_list().unwrap_or_default() {
+                        if let Some(s) = value.literal().and_then(|lit| lit.kind.str()) {
+                            expected.insert(Some(s.to_string()));
+                        } else if value.has_name(sym::none) && value.meta_item_list().is_some() {
+                            expected.insert(None);
+                        } else if value.has_name(sym::any) && value.meta_item_list().is_some() {
+                            any = true;
+                        } else {
+                            error!(
+                                "`values()` arguments must be string literals, `none()` or `any()`"
+                            );
+                        }
+                    }
+                    values = Some(if any {
+                        ExpectedValues::Any
+                    } else {
+                        ExpectedValues::Some(expected)
+                    });
+                } else {
+                    error!("`cfg()` arguments must be simple identifiers or `values(...)`");
+                }
+            }
+
+            if names.is_empty() && values.is_some() {
+                error!("`values()` requires at least one name");
+            }
+
+            // A name declared without `values()` is expected to be used without a value.
+            let values =
+                values.unwrap_or_else(|| ExpectedValues::Some(std::iter::once(None).collect()));
+            for name in names {
+                let entry = check_cfg
+                    .expecteds
+                    .entry(name)
+                    .or_insert_with(|| ExpectedValues::Some(FxHashSet::default()));
+                match (entry, &values) {
+                    (ExpectedValues::Some(existing), ExpectedValues::Some(new)) => {
+                        existing.extend(new.iter().cloned())
+                    }
+                    (entry, _) => *entry = ExpectedValues::Any,
+                }
+            }
+        }
+
+        check_cfg
+    })
+}
+
 /// The compiler configuration
 pub struct Config {
     /// Command line options
@@ -130,6 +231,8 @@ pub struct Config {
 
     /// cfg! configuration in addition to the default ones
     pub crate_cfg: FxHashSet<(String, Option<String>)>,
+    /// `--check-cfg` options, i.e. the cfg names and values that are expected to be used
+    pub crate_check_cfg: CheckCfg,
 
     pub input: Input,
     pub input_path: Option<PathBuf>,
@@ -173,6 +276,7 @@ pub fn create_compiler_and_run<R>(config: Config, f: impl FnOnce(&Compiler) -> R
     let (mut sess, codegen_backend) = util::create_session(
         config.opts,
         config.crate_cfg,
+        config.crate_check_cfg,
         config.diagnostic_output,
         config.file_loader,
         config.input_path.clone(),
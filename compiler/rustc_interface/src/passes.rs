@@ -1053,6 +1053,10 @@ pub fn start_codegen<'tcx>(
         codegen_backend.codegen_crate(tcx, metadata, need_metadata_module)
     });
 
+    // This must run after all lints have been emitted, including the ones emitted while
+    // collecting the mono items during codegen.
+    tcx.sess.time("check_lint_expectations", || rustc_lint::check_expectations(tcx));
+
     // Don't run these test assertions when not doing codegen. Compiletest tries to build
     // build-fail tests in check mode first and expects it to not give an error in that case.
     if tcx.sess.opts.output_types.should_codegen() {
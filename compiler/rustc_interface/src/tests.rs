@@ -1,7 +1,11 @@
-use crate::interface::parse_cfgspecs;
+use crate::interface::{parse_cfgspecs, parse_check_cfg};
 
 use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
+use rustc_session::config::DumpMonoStatsFormat;
+use rustc_session::config::ExpectedValues;
+use rustc_session::config::IncrementalVerifyIch;
 use rustc_session::config::InstrumentCoverage;
 use rustc_session::config::Strip;
 use rustc_session::config::{build_configuration, build_session_options, to_crate_config};
@@ -128,6 +132,30 @@ fn test_switch_implies_cfg_test_unless_cfg_test() {
     });
 }
 
+// Repeated `--check-cfg` options combine the values expected for each name
+#[test]
+fn test_check_cfg_merges_values() {
+    let check_cfg = parse_check_cfg(vec![
+        r#"cfg(feature, values("a"))"#.to_string(),
+        r#"cfg(feature, values("b", none()))"#.to_string(),
+        "cfg(foo, bar)".to_string(),
+        "cfg(bar, values(any()))".to_string(),
+    ]);
+    assert!(check_cfg.exhaustive_names);
+    let feature_values = FxHashSet::from_iter([Some("a".to_string()), Some("b".to_string()), None]);
+    assert_eq!(check_cfg.expecteds["feature"], ExpectedValues::Some(feature_values));
+    assert_eq!(check_cfg.expecteds["foo"], ExpectedValues::Some(FxHashSet::from_iter([None])));
+    assert_eq!(check_cfg.expecteds["bar"], ExpectedValues::Any);
+}
+
+// Without any `--check-cfg`, cfg names are not checked at all
+#[test]
+fn test_check_cfg_disabled_by_default() {
+    let check_cfg = parse_check_cfg(vec![]);
+    assert!(!check_cfg.exhaustive_names);
+    assert!(check_cfg.expecteds.is_empty());
+}
+
 #[test]
 fn test_can_print_warnings() {
     rustc_span::create_default_session_globals_then(|| {
@@ -637,6 +665,7 @@ macro_rules! untracked {
     untracked!(ast_json_noexpand, true);
     untracked!(borrowck, String::from("other"));
     untracked!(deduplicate_diagnostics, false);
+    untracked!(dep_graph_filter, Some(String::from("typeck,optimized_mir")));
     untracked!(dep_tasks, true);
     untracked!(dont_buffer_diagnostics, true);
     untracked!(dump_dep_graph, true);
@@ -645,6 +674,8 @@ macro_rules! untracked {
     untracked!(dump_mir_dir, String::from("abc"));
     untracked!(dump_mir_exclude_pass_number, true);
     untracked!(dump_mir_graphviz, true);
+    untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-stats".into())));
+    untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
     untracked!(emit_future_incompat_report, true);
     untracked!(emit_stack_sizes, true);
     untracked!(future_incompat_test, true);
@@ -652,7 +683,8 @@ macro_rules! untracked {
     untracked!(identify_regions, true);
     untracked!(incremental_ignore_spans, true);
     untracked!(incremental_info, true);
-    untracked!(incremental_verify_ich, true);
+    untracked!(incremental_verify_ich, Some(IncrementalVerifyIch::Dump(None)));
+    untracked!(incremental_verify_ich_force_mismatch, true);
     untracked!(input_stats, true);
     untracked!(keep_hygiene_data, true);
     untracked!(link_native_libraries, false);
@@ -680,6 +712,8 @@ macro_rules! untracked {
     untracked!(save_analysis, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
+    untracked!(self_profile_format, SelfProfileFormat::Chrome);
+    untracked!(self_profile_summarize, true);
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
     untracked!(strip, Strip::Debuginfo);
@@ -688,6 +722,7 @@ macro_rules! untracked {
     untracked!(time, true);
     untracked!(time_llvm_passes, true);
     untracked!(time_passes, true);
+    untracked!(time_passes_format, TimePassesFormat::Json);
     untracked!(trace_macros, true);
     untracked!(trim_diagnostic_paths, false);
     untracked!(ui_testing, true);
@@ -795,3 +830,19 @@ fn test_edition_parsing() {
     let (sessopts, _) = build_session_options_and_crate_config(matches);
     assert!(sessopts.edition == Edition::Edition2018)
 }
+
+#[test]
+fn test_incremental_verify_ich_parsing() {
+    let parse = |arg: &str| {
+        let matches = optgroups().parse(&[arg.to_string()]).unwrap();
+        build_session_options_and_crate_config(matches).0.debugging_opts.incremental_verify_ich
+    };
+    assert_eq!(parse("-Zincremental-verify-ich"), Some(IncrementalVerifyIch::Verify));
+    assert_eq!(parse("-Zincremental-verify-ich=yes"), Some(IncrementalVerifyIch::Verify));
+    assert_eq!(parse("-Zincremental-verify-ich=no"), None);
+    assert_eq!(parse("-Zincremental-verify-ich=dump"), Some(IncrementalVerifyIch::Dump(None)));
+    assert_eq!(
+        parse("-Zincremental-verify-ich=dump=reports"),
+        Some(IncrementalVerifyIch::Dump(Some(PathBuf::from("reports"))))
+    );
+}
@@ -369,6 +369,9 @@ pub fn check_lint_name_cmdline(
                 "requested on the command line with `{} {}`",
                 match level {
                     Level::Allow => "-A",
+                    Level::Expect => {
+                        unreachable!("the `expect` level cannot be set on the command line")
+                    }
                     Level::Warn => "-W",
                     Level::ForceWarn => "--force-warn",
                     Level::Deny => "-D",
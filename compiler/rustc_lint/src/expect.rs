@@ -0,0 +1,41 @@
+use rustc_middle::lint::struct_lint_level;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::lint::builtin::UNFULFILLED_LINT_EXPECTATIONS;
+
+/// Emits `unfulfilled_lint_expectations` for every `#[expect]` attribute that
+/// did not suppress any lint. This has to run after all lints have been emitted.
+pub fn check_expectations(tcx: TyCtxt<'_>) {
+    if !tcx.features().lint_reasons {
+        return;
+    }
+
+    let fulfilled_expectations = tcx.sess.diagnostic().steal_fulfilled_expectations();
+    let lint_levels = tcx.lint_levels(());
+
+    for expectation in &lint_levels.expectations {
+        if fulfilled_expectations.contains(&expectation.span) {
+            continue;
+        }
+
+        let (level, src) = lint_levels.sets.get_lint_level(
+            UNFULFILLED_LINT_EXPECTATIONS,
+            expectation.lint_stack,
+            None,
+            tcx.sess,
+        );
+        struct_lint_level(
+            tcx.sess,
+            UNFULFILLED_LINT_EXPECTATIONS,
+            level,
+            src,
+            Some(expectation.span.into()),
+            |lint| {
+                let mut diag = lint.build("this lint expectation is unfulfilled");
+                if let Some(rationale) = expectation.reason {
+                    diag.note(&rationale.as_str());
+                }
+                diag.emit();
+            },
+        );
+    }
+}
@@ -11,8 +11,8 @@
 use rustc_middle::lint::LevelAndSource;
 use rustc_middle::lint::LintDiagnosticBuilder;
 use rustc_middle::lint::{
-    struct_lint_level, LintLevelMap, LintLevelSets, LintLevelSource, LintSet, LintStackIndex,
-    COMMAND_LINE,
+    struct_lint_level, LintExpectation, LintLevelMap, LintLevelSets, LintLevelSource, LintSet,
+    LintStackIndex, COMMAND_LINE,
 };
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
@@ -50,6 +50,7 @@ pub struct LintLevelsBuilder<'s> {
     sess: &'s Session,
     sets: LintLevelSets,
     id_to_set: FxHashMap<HirId, LintStackIndex>,
+    expectations: Vec<LintExpectation>,
     cur: LintStackIndex,
     warn_about_weird_lints: bool,
     store: &'s LintStore,
@@ -73,6 +74,7 @@ pub fn new(
             sets: LintLevelSets::new(),
             cur: COMMAND_LINE,
             id_to_set: Default::default(),
+            expectations: Vec::new(),
             warn_about_weird_lints,
             store,
             crate_attrs,
@@ -228,6 +230,7 @@ pub(crate) fn push(
         is_crate_node: bool,
     ) -> BuilderPush {
         let mut specs = FxHashMap::default();
+        let mut expectations = Vec::new();
         let sess = self.sess;
         let bad_attr = |span| struct_span_err!(sess, span, E0452, "malformed lint attribute input");
         for attr in attrs {
@@ -327,6 +330,9 @@ pub(crate) fn push(
                             self.check_gated_lint(id, attr.span);
                             self.insert_spec(&mut specs, id, (level, src));
                         }
+                        if level == Level::Expect {
+                            expectations.push((sp, reason));
+                        }
                     }
 
                     CheckLintNameResult::Tool(result) => {
@@ -342,6 +348,9 @@ pub(crate) fn push(
                                 for id in ids {
                                     self.insert_spec(&mut specs, *id, (level, src));
                                 }
+                                if level == Level::Expect {
+                                    expectations.push((sp, reason));
+                                }
                             }
                             Err((Some(ids), ref new_lint_name)) => {
                                 let lint = builtin::RENAMED_AND_REMOVED_LINTS;
@@ -378,6 +387,9 @@ pub(crate) fn push(
                                 for id in ids {
                                     self.insert_spec(&mut specs, *id, (level, src));
                                 }
+                                if level == Level::Expect {
+                                    expectations.push((sp, reason));
+                                }
                             }
                             Err((None, _)) => {
                                 // If Tool(Err(None, _)) is returned, then either the lint does not
@@ -471,6 +483,9 @@ pub(crate) fn push(
                             self.check_gated_lint(id, attr.span);
                             self.insert_spec(&mut specs, id, (level, src));
                         }
+                        if level == Level::Expect {
+                            expectations.push((sp, reason));
+                        }
                     } else {
                         panic!("renamed lint does not exist: {}", new_name);
                     }
@@ -517,6 +532,13 @@ pub(crate) fn push(
             self.cur = self.sets.list.push(LintSet { specs, parent: prev });
         }
 
+        let lint_stack = self.cur;
+        self.expectations.extend(expectations.into_iter().map(|(span, reason)| LintExpectation {
+            span,
+            reason,
+            lint_stack,
+        }));
+
         BuilderPush { prev, changed: prev != self.cur }
     }
 
@@ -564,7 +586,7 @@ pub fn register_id(&mut self, id: HirId) {
     }
 
     pub fn build_map(self) -> LintLevelMap {
-        LintLevelMap { sets: self.sets, id_to_set: self.id_to_set }
+        LintLevelMap { sets: self.sets, id_to_set: self.id_to_set, expectations: self.expectations }
     }
 }
 
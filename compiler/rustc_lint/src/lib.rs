@@ -48,6 +48,7 @@
 pub mod builtin;
 mod context;
 mod early;
+mod expect;
 mod internal;
 mod late;
 mod levels;
@@ -58,6 +59,7 @@
 mod noop_method_call;
 mod passes;
 mod redundant_semicolon;
+mod reference_casting;
 mod traits;
 mod types;
 mod unused;
@@ -82,6 +84,7 @@
 use nonstandard_style::*;
 use noop_method_call::*;
 use redundant_semicolon::*;
+use reference_casting::*;
 use traits::*;
 use types::*;
 use unused::*;
@@ -90,6 +93,7 @@
 pub use builtin::SoftLints;
 pub use context::{CheckLintNameResult, EarlyContext, LateContext, LintContext, LintStore};
 pub use early::check_ast_crate;
+pub use expect::check_expectations;
 pub use late::check_crate;
 pub use passes::{EarlyLintPass, LateLintPass};
 pub use rustc_session::lint::Level::{self, *};
@@ -205,6 +209,8 @@ macro_rules! late_lint_mod_passes {
                 ExplicitOutlivesRequirements: ExplicitOutlivesRequirements,
                 InvalidValue: InvalidValue,
                 DerefNullPtr: DerefNullPtr,
+                ProvenanceCasts: ProvenanceCasts,
+                InvalidReferenceCasting: InvalidReferenceCasting,
             ]
         );
     };
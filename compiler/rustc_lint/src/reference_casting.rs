@@ -0,0 +1,208 @@
+use crate::context::LintContext;
+use crate::LateContext;
+use crate::LateLintPass;
+use rustc_hir::def::Res;
+use rustc_hir::{BindingAnnotation, BorrowKind, Expr, ExprKind, Mutability, Node, PatKind};
+use rustc_hir::{QPath, UnOp};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::symbol::sym;
+use rustc_span::Span;
+
+declare_lint! {
+    /// The `invalid_reference_casting` lint checks for writes through a `*mut T`
+    /// that was obtained by casting a `&T`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// fn x(r: &i32) {
+    ///     unsafe {
+    ///         *(r as *const i32 as *mut i32) += 1;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Casting `&T` to `*mut T` and writing through the resulting pointer is
+    /// undefined behavior, because the data behind a shared reference is
+    /// assumed to be immutable. This is the case even when the cast goes
+    /// through intermediate `*const T` pointers, `cast()` calls or
+    /// `transmute`.
+    ///
+    /// Data that needs to be mutated through a shared reference has to be
+    /// wrapped in an [`UnsafeCell`] (or one of the types built on top of it,
+    /// like [`Cell`]).
+    ///
+    /// [`UnsafeCell`]: https://doc.rust-lang.org/std/cell/struct.UnsafeCell.html
+    /// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+    pub INVALID_REFERENCE_CASTING,
+    Deny,
+    "writes through a `*mut T` that was cast from a `&T`"
+}
+
+declare_lint_pass!(InvalidReferenceCasting => [INVALID_REFERENCE_CASTING]);
+
+impl<'tcx> LateLintPass<'tcx> for InvalidReferenceCasting {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ptr = match written_pointer(cx, expr) {
+            Some(ptr) => ptr,
+            None => return,
+        };
+        let (cast_span, ref_ty) = match find_ref_to_mut_ptr_cast(cx, ptr) {
+            Some(cast) => cast,
+            None => return,
+        };
+
+        cx.struct_span_lint(INVALID_REFERENCE_CASTING, expr.span, |lint| {
+            let msg = "writing through a pointer that was cast from a shared reference \
+                       is undefined behavior";
+            lint.build(msg)
+                .span_label(expr.span, "written to here")
+                .span_label(
+                    cast_span,
+                    format!("casting `{}` to a mutable pointer happens here", ref_ty),
+                )
+                .help(
+                    "consider using an `UnsafeCell` if the data needs to be mutated \
+                     through a shared reference",
+                )
+                .emit();
+        });
+    }
+}
+
+/// Returns the pointer that `expr` writes through, if `expr` is an assignment to a
+/// dereferenced pointer or a call to one of the `ptr::write` family of functions.
+fn written_pointer<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<&'tcx Expr<'tcx>> {
+    match expr.kind {
+        ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => {
+            let mut place = lhs;
+            loop {
+                place = match place.kind {
+                    ExprKind::Field(base, _) | ExprKind::Index(base, _) => base,
+                    ExprKind::Unary(UnOp::Deref, ptr) => return Some(ptr),
+                    _ => return None,
+                };
+            }
+        }
+        ExprKind::Call(path, [dst, ..]) => {
+            let def_id = match path.kind {
+                ExprKind::Path(ref qpath) => cx.qpath_res(qpath, path.hir_id).opt_def_id()?,
+                _ => return None,
+            };
+            [sym::ptr_write, sym::ptr_write_unaligned, sym::ptr_write_volatile, sym::ptr_replace]
+                .iter()
+                .any(|&item| cx.tcx.is_diagnostic_item(item, def_id))
+                .then_some(dst)
+        }
+        ExprKind::MethodCall(segment, _, [receiver, ..], _)
+            if matches!(
+                segment.ident.name,
+                sym::write | sym::write_unaligned | sym::write_volatile | sym::replace
+            ) && is_raw_ptr_method(cx, expr) =>
+        {
+            Some(receiver)
+        }
+        _ => None,
+    }
+}
+
+/// Walks back from `ptr` through pointer casts, `cast()` calls, `transmute`s, `&mut *`
+/// reborrows and immutable `let` bindings. If `ptr` turns out to be derived from a
+/// `&T` (where `T` has no interior mutability), returns the span of the expression
+/// that turned it into a `*mut` pointer along with the type of the reference.
+fn find_ref_to_mut_ptr_cast<'tcx>(
+    cx: &LateContext<'tcx>,
+    mut expr: &'tcx Expr<'tcx>,
+) -> Option<(Span, Ty<'tcx>)> {
+    let typeck_results = cx.typeck_results();
+    let is_mut_ptr = |e: &Expr<'_>| {
+        matches!(
+            typeck_results.expr_ty(e).kind(),
+            ty::RawPtr(ty::TypeAndMut { mutbl: Mutability::Mut, .. })
+        )
+    };
+
+    let mut cast_span = None;
+    loop {
+        let next = match expr.kind {
+            ExprKind::Cast(inner, _) => inner,
+            ExprKind::MethodCall(segment, _, [receiver], _)
+                if segment.ident.name == sym::cast && is_raw_ptr_method(cx, expr) =>
+            {
+                receiver
+            }
+            ExprKind::Call(path, [arg]) if is_transmute(cx, path) => arg,
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner) => match inner.kind {
+                ExprKind::Unary(UnOp::Deref, ptr) => ptr,
+                _ => return None,
+            },
+            ExprKind::Path(QPath::Resolved(None, path)) => match local_init(cx, path.res) {
+                Some(init) => init,
+                None => break,
+            },
+            _ => break,
+        };
+        if is_mut_ptr(expr) && !is_mut_ptr(next) {
+            cast_span = Some(expr.span);
+        }
+        expr = next;
+    }
+
+    let ref_ty = typeck_results.expr_ty(expr);
+    match ref_ty.kind() {
+        ty::Ref(_, inner_ty, Mutability::Not)
+            if inner_ty.is_freeze(cx.tcx.at(expr.span), cx.param_env) =>
+        {
+            cast_span.map(|span| (span, ref_ty))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the initializer of the local `res` refers to, if it was bound by an
+/// immutable `let` binding.
+fn local_init<'tcx>(cx: &LateContext<'tcx>, res: Res) -> Option<&'tcx Expr<'tcx>> {
+    let hir_id = match res {
+        Res::Local(hir_id) => hir_id,
+        _ => return None,
+    };
+    let hir = cx.tcx.hir();
+    let is_immutable_binding = match hir.find(hir_id) {
+        Some(Node::Binding(pat)) => {
+            matches!(pat.kind, PatKind::Binding(BindingAnnotation::Unannotated, ..))
+        }
+        _ => false,
+    };
+    match hir.find(hir.get_parent_node(hir_id)) {
+        Some(Node::Local(local)) if is_immutable_binding && local.pat.hir_id == hir_id => {
+            local.init
+        }
+        _ => None,
+    }
+}
+
+fn is_raw_ptr_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|def_id| cx.tcx.impl_of_method(def_id))
+        .map_or(false, |impl_id| {
+            cx.tcx.trait_id_of_impl(impl_id).is_none() && cx.tcx.type_of(impl_id).is_unsafe_ptr()
+        })
+}
+
+fn is_transmute(cx: &LateContext<'_>, path: &Expr<'_>) -> bool {
+    match path.kind {
+        ExprKind::Path(ref qpath) => cx
+            .qpath_res(qpath, path.hir_id)
+            .opt_def_id()
+            .map_or(false, |def_id| cx.tcx.is_diagnostic_item(sym::transmute, def_id)),
+        _ => false,
+    }
+}
@@ -1614,3 +1614,186 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         Self::check_atomic_compare_exchange(cx, expr);
     }
 }
+
+declare_lint! {
+    /// The `fuzzy_provenance_casts` lint detects an `as` cast from an integer
+    /// to a pointer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(strict_provenance)]
+    /// #![warn(fuzzy_provenance_casts)]
+    ///
+    /// fn main() {
+    ///     let _dangling = 16_usize as *const u8;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// This lint is part of the strict provenance effort, see [issue #95228].
+    /// Besides an *address*, a pointer carries a *provenance* that describes
+    /// which memory it is allowed to access. An integer has no provenance, so
+    /// casting it to a pointer forces the compiler to guess one. This hinders
+    /// optimizations and tools that check pointer validity at runtime (such
+    /// as Miri, or CHERI platforms).
+    ///
+    /// Prefer [`pointer::with_addr`] on a pointer that already has the right
+    /// provenance. Code that relies on exposed provenance can use
+    /// [`ptr::from_exposed_addr`] instead to make that explicit.
+    ///
+    /// [issue #95228]: https://github.com/rust-lang/rust/issues/95228
+    /// [`pointer::with_addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.with_addr
+    /// [`ptr::from_exposed_addr`]: https://doc.rust-lang.org/core/ptr/fn.from_exposed_addr.html
+    FUZZY_PROVENANCE_CASTS,
+    Allow,
+    "a fuzzy integer to pointer cast is used",
+    @feature_gate = sym::strict_provenance;
+}
+
+declare_lint! {
+    /// The `lossy_provenance_casts` lint detects an `as` cast from a pointer
+    /// to an integer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(strict_provenance)]
+    /// #![warn(lossy_provenance_casts)]
+    ///
+    /// fn main() {
+    ///     let x: u8 = 37;
+    ///     let _addr: usize = &x as *const u8 as usize;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// This lint is part of the strict provenance effort, see [issue #95228].
+    /// Casting a pointer to an integer loses the *provenance* of the pointer,
+    /// which describes which memory it is allowed to access, while implicitly
+    /// "exposing" it so that a later integer to pointer cast may pick it up
+    /// again.
+    ///
+    /// Prefer [`pointer::addr`], which only returns the address and makes the
+    /// loss of provenance explicit. Code that needs to cast the address back to
+    /// a pointer later can use [`pointer::expose_addr`] instead.
+    ///
+    /// [issue #95228]: https://github.com/rust-lang/rust/issues/95228
+    /// [`pointer::addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.addr
+    /// [`pointer::expose_addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.expose_addr
+    LOSSY_PROVENANCE_CASTS,
+    Allow,
+    "a lossy pointer to integer cast is used",
+    @feature_gate = sym::strict_provenance;
+}
+
+declare_lint_pass!(ProvenanceCasts => [FUZZY_PROVENANCE_CASTS, LOSSY_PROVENANCE_CASTS]);
+
+impl ProvenanceCasts {
+    fn lint_int_to_ptr<'tcx>(
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'tcx>,
+        cast_expr: &'tcx Expr<'tcx>,
+        cast_ty: &'tcx hir::Ty<'tcx>,
+    ) {
+        let typeck_results = cx.typeck_results();
+        cx.struct_span_lint(FUZZY_PROVENANCE_CASTS, expr.span, |lint| {
+            let msg = format!(
+                "strict provenance disallows casting integer `{}` to pointer `{}`",
+                typeck_results.expr_ty(cast_expr),
+                typeck_results.expr_ty(expr),
+            );
+            lint.build(&msg)
+                .multipart_suggestion(
+                    "use `.with_addr()` to adjust a valid pointer in the same allocation, \
+                     to this address",
+                    vec![
+                        (cast_expr.span.shrink_to_lo(), "(...).with_addr(".to_string()),
+                        (cast_expr.span.shrink_to_hi().to(cast_ty.span), ")".to_string()),
+                    ],
+                    Applicability::HasPlaceholders,
+                )
+                .help(
+                    "if you can't comply with strict provenance and don't have a pointer with \
+                     the correct provenance you can use `std::ptr::from_exposed_addr()` instead",
+                )
+                .emit();
+        });
+    }
+
+    fn lint_ptr_to_int<'tcx>(
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'tcx>,
+        cast_expr: &'tcx Expr<'tcx>,
+        cast_ty: &'tcx hir::Ty<'tcx>,
+    ) {
+        let typeck_results = cx.typeck_results();
+        cx.struct_span_lint(LOSSY_PROVENANCE_CASTS, expr.span, |lint| {
+            let target_ty = typeck_results.expr_ty(expr);
+            let msg = format!(
+                "under strict provenance it is considered bad style to cast pointer `{}` \
+                 to integer `{}`",
+                typeck_results.expr_ty(cast_expr),
+                target_ty,
+            );
+            let mut diag = lint.build(&msg);
+
+            // `.addr()` always yields a `usize`, so keep casting to other integer types.
+            let scalar_cast = match target_ty.kind() {
+                ty::Uint(ty::UintTy::Usize) => String::new(),
+                _ => format!(" as {}", target_ty),
+            };
+            let suggestion_msg = "use `.addr()` to obtain the address of a pointer";
+            let cast_span = cast_expr.span.shrink_to_hi().to(cast_ty.span);
+            if cast_expr.precedence().order() < ast::util::parser::PREC_POSTFIX {
+                diag.multipart_suggestion(
+                    suggestion_msg,
+                    vec![
+                        (cast_expr.span.shrink_to_lo(), "(".to_string()),
+                        (cast_span, format!(").addr(){}", scalar_cast)),
+                    ],
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                diag.span_suggestion(
+                    cast_span,
+                    suggestion_msg,
+                    format!(".addr(){}", scalar_cast),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+
+            diag.help(
+                "if you can't comply with strict provenance and need to expose the pointer \
+                 provenance you can use `.expose_addr()` instead",
+            );
+            diag.emit();
+        });
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ProvenanceCasts {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (cast_expr, cast_ty) = match expr.kind {
+            ExprKind::Cast(cast_expr, cast_ty) => (cast_expr, cast_ty),
+            _ => return,
+        };
+
+        let typeck_results = cx.typeck_results();
+        match (typeck_results.expr_ty(cast_expr).kind(), typeck_results.expr_ty(expr).kind()) {
+            (ty::Int(_) | ty::Uint(_), ty::RawPtr(_)) => {
+                Self::lint_int_to_ptr(cx, expr, cast_expr, cast_ty)
+            }
+            (ty::RawPtr(_), ty::Int(_) | ty::Uint(_)) => {
+                Self::lint_ptr_to_int(cx, expr, cast_expr, cast_ty)
+            }
+            _ => {}
+        }
+    }
+}
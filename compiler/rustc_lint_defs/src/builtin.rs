@@ -522,6 +522,69 @@
     "detect unused, unexported items"
 }
 
+declare_lint! {
+    /// The `unused_tuple_struct_fields` lint detects fields of tuple structs
+    /// that are never read.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #[warn(unused_tuple_struct_fields)]
+    /// struct S(i32, i32, i32);
+    /// let s = S(1, 2, 3);
+    /// let _ = (s.0, s.2);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Tuple struct fields that are never read anywhere may indicate a
+    /// mistake or unfinished code. To silence this warning, consider
+    /// removing the unused field, or changing its type to `()` to preserve
+    /// the numbering of the remaining fields.
+    ///
+    /// This lint is "allow" by default because positional fields used to be
+    /// exempt from the `dead_code` lint, and a lot of existing code has tuple
+    /// struct fields that are only ever constructed.
+    pub UNUSED_TUPLE_STRUCT_FIELDS,
+    Allow,
+    "detects tuple struct fields that are never read"
+}
+
+declare_lint! {
+    /// The `fields_only_read_by_derives` lint detects fields that are only
+    /// read by derived impls of traits like `Debug` and `Clone`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #[warn(fields_only_read_by_derives)]
+    /// #[derive(Debug)]
+    /// struct S {
+    ///     a: i32,
+    ///     b: i32,
+    /// }
+    /// let s = S { a: 1, b: 2 };
+    /// println!("{:?} {}", s, s.a);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The `dead_code` lint counts the field reads in derived impls as uses
+    /// of the fields, so a field that is only ever printed with `{:?}` or
+    /// cloned along with its struct is not reported. Such fields are often
+    /// left over from earlier versions of the code, and may be removed.
+    ///
+    /// This lint is "allow" by default because printing or cloning a field
+    /// is sometimes its only intended use.
+    pub FIELDS_ONLY_READ_BY_DERIVES,
+    Allow,
+    "detects fields that are only read by derived impls"
+}
+
 declare_lint! {
     /// The `unused_attributes` lint detects attributes that were not used by
     /// the compiler.
@@ -2966,6 +3029,8 @@
         UNUSED_VARIABLES,
         UNUSED_ASSIGNMENTS,
         DEAD_CODE,
+        UNUSED_TUPLE_STRUCT_FIELDS,
+        FIELDS_ONLY_READ_BY_DERIVES,
         UNREACHABLE_CODE,
         UNREACHABLE_PATTERNS,
         OVERLAPPING_RANGE_ENDPOINTS,
@@ -3042,6 +3107,9 @@
         RUST_2021_PREFIXES_INCOMPATIBLE_SYNTAX,
         UNSUPPORTED_CALLING_CONVENTIONS,
         BREAK_WITH_LABEL_AND_LOOP,
+        UNFULFILLED_LINT_EXPECTATIONS,
+        NON_EXHAUSTIVE_OMITTED_PATTERNS,
+        UNEXPECTED_CFGS,
     ]
 }
 
@@ -3444,3 +3512,131 @@
     Warn,
     "`break` expression with label and unlabeled loop as value expression"
 }
+
+declare_lint! {
+    /// The `unfulfilled_lint_expectations` lint detects lint expectations
+    /// that were never fulfilled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![feature(lint_reasons)]
+    ///
+    /// #[expect(unused_variables)]
+    /// let x = 10;
+    /// println!("{}", x);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The `#[expect]` attribute can be used to suppress a lint while
+    /// expecting it to be emitted in the annotated code. If the lint is
+    /// never emitted, the expectation is unfulfilled and the attribute can
+    /// most likely be removed. This lint is emitted at the lint name inside
+    /// of the attribute, along with the `reason` of the attribute if one
+    /// was given.
+    ///
+    /// This lint can be allowed with `#[allow(unfulfilled_lint_expectations)]`
+    /// on the same item as the expectation or on an enclosing one.
+    pub UNFULFILLED_LINT_EXPECTATIONS,
+    Warn,
+    "unfulfilled lint expectation",
+    @feature_gate = rustc_span::sym::lint_reasons;
+}
+
+declare_lint! {
+    /// The `non_exhaustive_omitted_patterns` lint detects when a wildcard (`_`) in a match on
+    /// a `#[non_exhaustive]` enum from another crate covers some of the variants the enum has.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs separate crate)
+    /// // crate A
+    /// #[non_exhaustive]
+    /// pub enum Bar {
+    ///     A,
+    ///     B, // added variant in non breaking change
+    /// }
+    ///
+    /// // in crate B
+    /// match Bar::A {
+    ///     Bar::A => {},
+    ///     #[warn(non_exhaustive_omitted_patterns)]
+    ///     _ => {},
+    /// }
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: some variants are not matched explicitly
+    ///  --> src/main.rs:4:5
+    ///   |
+    /// 4 |     _ => {},
+    ///   |     ^ pattern `B` not covered
+    ///   |
+    /// note: the lint level is defined here
+    ///  --> src/main.rs:3:12
+    ///   |
+    /// 3 |     #[warn(non_exhaustive_omitted_patterns)]
+    ///   |            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ///   = help: ensure that all variants are matched explicitly by adding the suggested match arms
+    ///   = note: the matched value is of type `Bar` and the `non_exhaustive_omitted_patterns` lint
+    ///           was enabled
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Enums tagged with `#[non_exhaustive]` force the user to add a (potentially redundant)
+    /// wildcard when pattern-matching, to allow for future addition of variants. The
+    /// `non_exhaustive_omitted_patterns` lint detects when such a wildcard happens to actually
+    /// catch some variants. In other words, when the match without the wildcard would not be
+    /// exhaustive. This lets the user be informed when new variants are added to the enum in
+    /// the version of the dependency they are using.
+    pub NON_EXHAUSTIVE_OMITTED_PATTERNS,
+    Allow,
+    "detect when variants of enums marked `non_exhaustive` are missed"
+}
+
+declare_lint! {
+    /// The `unexpected_cfgs` lint detects unexpected conditional compilation conditions.
+    ///
+    /// ### Example
+    ///
+    /// ```text
+    /// rustc --check-cfg 'cfg()'
+    /// ```
+    ///
+    /// ```rust,ignore (needs command line option)
+    /// #[cfg(widnows)]
+    /// fn foo() {}
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: unexpected `cfg` condition name
+    ///  --> lint_example.rs:1:7
+    ///   |
+    /// 1 | #[cfg(widnows)]
+    ///   |       ^^^^^^^
+    ///   |
+    ///   = note: `#[warn(unexpected_cfgs)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// This lint is only active when a `--check-cfg` option has been passed to the
+    /// compiler and triggers whenever an unexpected condition name or value is used
+    /// in `#[cfg]`, `#[cfg_attr]` or `cfg!`. The known condition names include the
+    /// ones set by the compiler itself (like `unix` or `target_os`) as well as the
+    /// ones declared with `--check-cfg`.
+    ///
+    /// Without this lint, a misspelled condition name or value, or a condition that
+    /// no build ever sets, silently compiles the code out.
+    pub UNEXPECTED_CFGS,
+    Warn,
+    "detects unexpected names and values in `#[cfg]` conditions"
+}
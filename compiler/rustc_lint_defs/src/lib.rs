@@ -50,6 +50,9 @@ pub enum Applicability {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
 pub enum Level {
     Allow,
+    /// Like `Allow`, but the lint is expected to be emitted at least once, and
+    /// `unfulfilled_lint_expectations` is emitted if it never is (RFC 2383).
+    Expect,
     Warn,
     ForceWarn,
     Deny,
@@ -63,6 +66,7 @@ impl Level {
     pub fn as_str(self) -> &'static str {
         match self {
             Level::Allow => "allow",
+            Level::Expect => "expect",
             Level::Warn => "warn",
             Level::ForceWarn => "force-warn",
             Level::Deny => "deny",
@@ -85,6 +89,7 @@ pub fn from_str(x: &str) -> Option<Level> {
     pub fn from_symbol(x: Symbol) -> Option<Level> {
         match x {
             sym::allow => Some(Level::Allow),
+            sym::expect => Some(Level::Expect),
             sym::warn => Some(Level::Warn),
             sym::deny => Some(Level::Deny),
             sym::forbid => Some(Level::Forbid),
@@ -151,10 +151,24 @@ pub fn get_lint_id_level(
     }
 }
 
+/// A lint level set by an `#[expect]` attribute (RFC 2383). The expectation is
+/// fulfilled once any lint is emitted at the `Expect` level because of it.
+#[derive(Clone, Copy, Debug, HashStable)]
+pub struct LintExpectation {
+    /// The span of the lint name in the attribute, which identifies the expectation.
+    pub span: Span,
+    /// The reason given in the attribute, if any.
+    pub reason: Option<Symbol>,
+    /// The lint stack of the node the attribute is placed on, used to determine the
+    /// level of `unfulfilled_lint_expectations` for this expectation.
+    pub lint_stack: LintStackIndex,
+}
+
 #[derive(Debug)]
 pub struct LintLevelMap {
     pub sets: LintLevelSets,
     pub id_to_set: FxHashMap<HirId, LintStackIndex>,
+    pub expectations: Vec<LintExpectation>,
 }
 
 impl LintLevelMap {
@@ -178,11 +192,14 @@ pub fn level_and_source(
 impl<'a> HashStable<StableHashingContext<'a>> for LintLevelMap {
     #[inline]
     fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHasher) {
-        let LintLevelMap { ref sets, ref id_to_set } = *self;
+        let LintLevelMap { ref sets, ref id_to_set, ref expectations } = *self;
 
         id_to_set.hash_stable(hcx, hasher);
 
-        hcx.while_hashing_spans(true, |hcx| sets.hash_stable(hcx, hasher))
+        hcx.while_hashing_spans(true, |hcx| {
+            sets.hash_stable(hcx, hasher);
+            expectations.hash_stable(hcx, hasher);
+        })
     }
 }
 
@@ -243,6 +260,22 @@ fn struct_lint_level_impl(
                     return;
                 }
             }
+            (Level::Expect, span) => {
+                // The lint is never shown, but emitting it fulfills the expectation
+                // of the `#[expect]` attribute that suppressed it.
+                let mut err = sess.struct_expect("", src.span());
+                if let Some(span) = span {
+                    err.set_span(span);
+                }
+                let name = lint.name_lower();
+                err.code(DiagnosticId::Lint {
+                    name,
+                    has_future_breakage,
+                    is_force_warn: false,
+                });
+                decorate(LintDiagnosticBuilder::new(err));
+                return;
+            }
             (Level::Warn, Some(span)) => sess.struct_span_warn(span, ""),
             (Level::Warn, None) => sess.struct_warn(""),
             (Level::ForceWarn, Some(span)) => sess.struct_span_force_warn(span, ""),
@@ -289,6 +322,9 @@ fn struct_lint_level_impl(
                     Level::Forbid => "-F",
                     Level::Allow => "-A",
                     Level::ForceWarn => "--force-warn",
+                    Level::Expect => {
+                        unreachable!("the `expect` level cannot be set on the command line")
+                    }
                 };
                 let hyphen_case_lint_name = name.replace("_", "-");
                 if lint_flag_val.as_str() == name {
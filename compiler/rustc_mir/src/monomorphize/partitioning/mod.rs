@@ -97,14 +97,21 @@
 
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::sync;
-use rustc_hir::def_id::DefIdSet;
+use rustc_hir::def_id::{DefId, DefIdSet, LOCAL_CRATE};
+use rustc_macros::Encodable;
 use rustc_middle::mir::mono::MonoItem;
 use rustc_middle::mir::mono::{CodegenUnit, Linkage};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
+use rustc_serialize::json::as_pretty_json;
+use rustc_session::config::{DumpMonoStatsFormat, SwitchWithOptPath};
 use rustc_span::symbol::Symbol;
 
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
 use crate::monomorphize::collector::InliningMap;
 use crate::monomorphize::collector::{self, MonoItemCollectionMode};
 
@@ -421,9 +428,140 @@ fn collect_and_partition_mono_items<'tcx>(
         }
     }
 
+    if let SwitchWithOptPath::Enabled(ref path) = tcx.sess.opts.debugging_opts.dump_mono_stats {
+        let output_directory = match path {
+            Some(path) => path.clone(),
+            None => tcx.output_filenames(()).out_directory.clone(),
+        };
+        tcx.sess.time("dump_mono_items_stats", || {
+            if let Err(err) = dump_mono_items_stats(tcx, codegen_units, &output_directory) {
+                tcx.sess.err(&format!("cannot emit monomorphization stats: {}", err));
+            }
+        });
+    }
+
     (tcx.arena.alloc(mono_items), codegen_units)
 }
 
+/// Statistics about all the monomorphizations of a single definition, as written out by
+/// `-Z dump-mono-stats`.
+#[derive(Encodable)]
+struct MonoItemStats {
+    /// The path of the definition, without any generic arguments.
+    name: String,
+    /// The crate the definition comes from.
+    source_crate: String,
+    /// The number of distinct instantiations of the definition.
+    instantiation_count: usize,
+    /// The number of copies of those instantiations across all codegen units.
+    copies: usize,
+    /// The estimated size of the largest instantiation.
+    size_estimate: usize,
+    /// The estimated size of all copies of all instantiations.
+    total_estimate: usize,
+}
+
+#[derive(Encodable)]
+struct MonoStatsReport {
+    /// The crate that instantiated the items.
+    crate_name: String,
+    codegen_unit_count: usize,
+    items: Vec<MonoItemStats>,
+}
+
+fn dump_mono_items_stats<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    codegen_units: &[CodegenUnit<'tcx>],
+    output_directory: &Path,
+) -> io::Result<()> {
+    let format = tcx.sess.opts.debugging_opts.dump_mono_stats_format;
+    let crate_name = tcx.crate_name(LOCAL_CRATE);
+    let output_path =
+        output_directory.join(format!("{}.mono_items.{}", crate_name, format.extension()));
+
+    // Items with `LocalCopy` instantiation mode end up in every codegen unit using them.
+    let mut copies: FxHashMap<MonoItem<'tcx>, usize> = Default::default();
+    for cgu in codegen_units {
+        for &item in cgu.items().keys() {
+            *copies.entry(item).or_default() += 1;
+        }
+    }
+
+    let mut items_per_def_id: FxHashMap<DefId, Vec<(MonoItem<'tcx>, usize)>> = Default::default();
+    for (item, copies) in copies {
+        let def_id = match item {
+            MonoItem::Fn(instance) => instance.def_id(),
+            MonoItem::Static(def_id) => def_id,
+            MonoItem::GlobalAsm(item_id) => item_id.def_id.to_def_id(),
+        };
+        items_per_def_id.entry(def_id).or_default().push((item, copies));
+    }
+
+    let mut items: Vec<_> = items_per_def_id
+        .into_iter()
+        .map(|(def_id, items)| {
+            let mut size_estimate = 0;
+            let mut total_estimate = 0;
+            let mut total_copies = 0;
+            for (item, copies) in &items {
+                let item_size = item.size_estimate(tcx);
+                size_estimate = size_estimate.max(item_size);
+                total_estimate += item_size * copies;
+                total_copies += copies;
+            }
+            MonoItemStats {
+                name: with_no_trimmed_paths(|| tcx.def_path_str(def_id)),
+                source_crate: tcx.crate_name(def_id.krate).to_string(),
+                instantiation_count: items.len(),
+                copies: total_copies,
+                size_estimate,
+                total_estimate,
+            }
+        })
+        .collect();
+    // Show the biggest contributors first.
+    items.sort_unstable_by(|a, b| {
+        b.total_estimate.cmp(&a.total_estimate).then_with(|| a.name.cmp(&b.name))
+    });
+
+    let report = MonoStatsReport {
+        crate_name: crate_name.to_string(),
+        codegen_unit_count: codegen_units.len(),
+        items,
+    };
+
+    fs::create_dir_all(output_directory)?;
+    let mut file = BufWriter::new(File::create(&output_path)?);
+    match format {
+        DumpMonoStatsFormat::Json => writeln!(file, "{}", as_pretty_json(&report))?,
+        DumpMonoStatsFormat::Markdown => {
+            writeln!(file, "# Monomorphization statistics for `{}`", report.crate_name)?;
+            writeln!(file)?;
+            writeln!(file, "Codegen units: {}", report.codegen_unit_count)?;
+            writeln!(file)?;
+            writeln!(
+                file,
+                "| Item | Source crate | Instantiations | Copies | Estimated size | \
+                 Estimated total size |"
+            )?;
+            writeln!(file, "| :--- | :--- | ---: | ---: | ---: | ---: |")?;
+            for item in &report.items {
+                writeln!(
+                    file,
+                    "| `{}` | {} | {} | {} | {} | {} |",
+                    item.name,
+                    item.source_crate,
+                    item.instantiation_count,
+                    item.copies,
+                    item.size_estimate,
+                    item.total_estimate,
+                )?;
+            }
+        }
+    }
+    file.flush()
+}
+
 fn codegened_and_inlined_items<'tcx>(tcx: TyCtxt<'tcx>, (): ()) -> &'tcx DefIdSet {
     let (items, cgus) = tcx.collect_and_partition_mono_items(());
     let mut visited = DefIdSet::default();
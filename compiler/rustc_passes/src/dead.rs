@@ -3,6 +3,7 @@
 // from live codes are live, and everything else is dead.
 
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::{pluralize, Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Res};
 use rustc_hir::def_id::{DefId, LocalDefId};
@@ -46,6 +47,14 @@ struct MarkSymbolVisitor<'tcx> {
     ignore_variant_stack: Vec<DefId>,
     // maps from tuple struct constructors to tuple struct items
     struct_constructors: FxHashMap<LocalDefId, LocalDefId>,
+    // whether the derived impls of `#[rustc_trivial_field_reads]` traits are skipped
+    skip_derived_impls: bool,
+    // the derived impl items that were skipped
+    skipped_derived_impl_items: Vec<LocalDefId>,
+    // maps from ADTs to the traits of their derived impls that were skipped
+    ignored_derived_traits: FxHashMap<LocalDefId, Vec<DefId>>,
+    // the items expecting `dead_code`, which are explored without being marked live
+    expected_dead_items: FxHashSet<LocalDefId>,
 }
 
 impl<'tcx> MarkSymbolVisitor<'tcx> {
@@ -130,7 +139,6 @@ fn handle_field_access(&mut self, lhs: &hir::Expr<'_>, hir_id: hir::HirId) {
         }
     }
 
-    #[allow(dead_code)] // FIXME(81658): should be used + lint reinstated after #83171 relands.
     fn handle_assign(&mut self, expr: &'tcx hir::Expr<'tcx>) {
         if self
             .typeck_results()
@@ -147,7 +155,6 @@ fn handle_assign(&mut self, expr: &'tcx hir::Expr<'tcx>) {
         }
     }
 
-    #[allow(dead_code)] // FIXME(81658): should be used + lint reinstated after #83171 relands.
     fn check_for_self_assign(&mut self, assign: &'tcx hir::Expr<'tcx>) {
         fn check_for_self_assign_helper(
             tcx: TyCtxt<'tcx>,
@@ -155,6 +162,17 @@ fn check_for_self_assign_helper(
             lhs: &'tcx hir::Expr<'tcx>,
             rhs: &'tcx hir::Expr<'tcx>,
         ) -> bool {
+            // Going through an overloaded `Deref`/`DerefMut` runs user code, which may return a
+            // different place on each call, so such an assignment isn't necessarily useless.
+            let is_overloaded_deref = |expr: &hir::Expr<'_>| {
+                typeck_results
+                    .expr_adjustments(expr)
+                    .iter()
+                    .any(|adj| matches!(adj.kind, ty::adjustment::Adjust::Deref(Some(_))))
+            };
+            if is_overloaded_deref(lhs) || is_overloaded_deref(rhs) {
+                return false;
+            }
             match (&lhs.kind, &rhs.kind) {
                 (hir::ExprKind::Path(ref qpath_l), hir::ExprKind::Path(ref qpath_r)) => {
                     if let (Res::Local(id_l), Res::Local(id_r)) = (
@@ -182,6 +200,8 @@ fn check_for_self_assign_helper(
         if let hir::ExprKind::Assign(lhs, rhs, _) = assign.kind {
             if check_for_self_assign_helper(self.tcx, self.typeck_results(), lhs, rhs)
                 && !assign.span.from_expansion()
+                && !lhs.span.from_expansion()
+                && !rhs.span.from_expansion()
             {
                 let is_field_assign = matches!(lhs.kind, hir::ExprKind::Field(..));
                 self.tcx.struct_span_lint_hir(
@@ -220,6 +240,62 @@ fn handle_field_pattern_match(
         }
     }
 
+    fn handle_tuple_field_pattern_match(
+        &mut self,
+        lhs: &hir::Pat<'_>,
+        res: Res,
+        pats: &[hir::Pat<'_>],
+        dotdot: Option<usize>,
+    ) {
+        let variant = match self.typeck_results().node_type(lhs.hir_id).kind() {
+            ty::Adt(adt, _) => adt.variant_of_res(res),
+            _ => span_bug!(lhs.span, "non-ADT in tuple struct pattern"),
+        };
+        // Subpatterns after a `..` refer to the last fields of the variant.
+        let dotdot = dotdot.unwrap_or(pats.len());
+        let missing = variant.fields.len() - pats.len();
+        for (idx, pat) in pats.iter().enumerate() {
+            if let PatKind::Wild = pat.kind {
+                continue;
+            }
+            let index = if idx < dotdot { idx } else { idx + missing };
+            self.insert_def_id(variant.fields[index].did);
+        }
+    }
+
+    /// Returns whether `impl_item` should be skipped because it is part of a `#[derive]`d impl
+    /// of a trait marked `#[rustc_trivial_field_reads]`. The skipped item and the trait are
+    /// recorded, so that the symbols only used by derived impls can be found afterwards.
+    fn should_ignore_impl_item(&mut self, impl_item: &hir::ImplItem<'_>) -> bool {
+        if !self.skip_derived_impls {
+            return false;
+        }
+
+        let impl_def_id = self.tcx.parent(impl_item.def_id.to_def_id()).unwrap();
+        if !self.tcx.has_attr(impl_def_id, sym::automatically_derived) {
+            return false;
+        }
+        let trait_def_id = match self.tcx.trait_id_of_impl(impl_def_id) {
+            Some(trait_def_id)
+                if self.tcx.has_attr(trait_def_id, sym::rustc_trivial_field_reads) =>
+            {
+                trait_def_id
+            }
+            _ => return false,
+        };
+
+        if let ty::Adt(adt, _) = self.tcx.type_of(impl_def_id).kind() {
+            if let Some(adt_def_id) = adt.did.as_local() {
+                let traits = self.ignored_derived_traits.entry(adt_def_id).or_default();
+                if !traits.contains(&trait_def_id) {
+                    traits.push(trait_def_id);
+                }
+            }
+        }
+        self.skipped_derived_impl_items.push(impl_item.def_id);
+        true
+    }
+
     fn mark_live_symbols(&mut self) {
         let mut scanned = FxHashSet::default();
         while let Some(id) = self.worklist.pop() {
@@ -227,12 +303,16 @@ fn mark_live_symbols(&mut self) {
                 continue;
             }
 
+            let is_expected_dead = self.expected_dead_items.contains(&id);
+
             // in the case of tuple struct constructors we want to check the item, not the generated
             // tuple struct constructor function
             let id = self.struct_constructors.get(&id).copied().unwrap_or(id);
 
             if let Some(node) = self.tcx.hir().find(self.tcx.hir().local_def_id_to_hir_id(id)) {
-                self.live_symbols.insert(id);
+                if !is_expected_dead {
+                    self.live_symbols.insert(id);
+                }
                 self.visit_node(node);
             }
         }
@@ -271,7 +351,9 @@ fn visit_node(&mut self, node: Node<'tcx>) {
                 intravisit::walk_trait_item(self, trait_item);
             }
             Node::ImplItem(impl_item) => {
-                intravisit::walk_impl_item(self, impl_item);
+                if !self.should_ignore_impl_item(impl_item) {
+                    intravisit::walk_impl_item(self, impl_item);
+                }
             }
             Node::ForeignItem(foreign_item) => {
                 intravisit::walk_foreign_item(self, &foreign_item);
@@ -340,6 +422,12 @@ fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
             hir::ExprKind::Field(ref lhs, ..) => {
                 self.handle_field_access(&lhs, expr.hir_id);
             }
+            hir::ExprKind::Assign(ref lhs, ref rhs, ..) => {
+                self.handle_assign(lhs);
+                self.check_for_self_assign(expr);
+                self.visit_expr(rhs);
+                return;
+            }
             hir::ExprKind::Struct(ref qpath, ref fields, _) => {
                 let res = self.typeck_results().qpath_res(qpath, expr.hir_id);
                 self.handle_res(res);
@@ -370,6 +458,10 @@ fn visit_pat(&mut self, pat: &'tcx hir::Pat<'tcx>) {
                 let res = self.typeck_results().qpath_res(path, pat.hir_id);
                 self.handle_field_pattern_match(pat, res, fields);
             }
+            PatKind::TupleStruct(ref qpath, ref fields, dotdot) => {
+                let res = self.typeck_results().qpath_res(qpath, pat.hir_id);
+                self.handle_tuple_field_pattern_match(pat, res, fields, dotdot);
+            }
             PatKind::Path(ref qpath) => {
                 let res = self.typeck_results().qpath_res(qpath, pat.hir_id);
                 self.handle_res(res);
@@ -400,20 +492,30 @@ fn visit_anon_const(&mut self, c: &'tcx hir::AnonConst) {
     }
 }
 
-fn has_allow_dead_code_or_lang_attr(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+/// How an item is kept alive by `has_allow_dead_code_or_lang_attr`.
+#[derive(Clone, Copy, PartialEq)]
+enum KeptAlive {
+    /// The item is live, e.g. it is a lang item or allows `dead_code`.
+    Always,
+    /// The item expects `dead_code`. What it uses is live, but the item itself
+    /// is still reported, which fulfills the expectation.
+    OnlyItsUses,
+}
+
+fn has_allow_dead_code_or_lang_attr(tcx: TyCtxt<'_>, id: hir::HirId) -> Option<KeptAlive> {
     let attrs = tcx.hir().attrs(id);
     if tcx.sess.contains_name(attrs, sym::lang) {
-        return true;
+        return Some(KeptAlive::Always);
     }
 
     // Stable attribute for #[lang = "panic_impl"]
     if tcx.sess.contains_name(attrs, sym::panic_handler) {
-        return true;
+        return Some(KeptAlive::Always);
     }
 
     // (To be) stable attribute for #[lang = "oom"]
     if tcx.sess.contains_name(attrs, sym::alloc_error_handler) {
-        return true;
+        return Some(KeptAlive::Always);
     }
 
     let def_id = tcx.hir().local_def_id(id);
@@ -422,10 +524,14 @@ fn has_allow_dead_code_or_lang_attr(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
     // #[used], #[no_mangle], #[export_name], etc also keeps the item alive
     // forcefully, e.g., for placing it in a specific section.
     if cg_attrs.contains_extern_indicator() || cg_attrs.flags.contains(CodegenFnAttrFlags::USED) {
-        return true;
+        return Some(KeptAlive::Always);
     }
 
-    tcx.lint_level_at_node(lint::builtin::DEAD_CODE, id).0 == lint::Allow
+    match tcx.lint_level_at_node(lint::builtin::DEAD_CODE, id).0 {
+        lint::Allow => Some(KeptAlive::Always),
+        lint::Expect => Some(KeptAlive::OnlyItsUses),
+        _ => None,
+    }
 }
 
 // This visitor seeds items that
@@ -438,30 +544,43 @@ fn has_allow_dead_code_or_lang_attr(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
 //           warning for both `f` and `g`.
 //     * Item annotated with #[lang=".."]
 //         - This is because lang items are always callable from elsewhere.
+//     * Item annotated with #[expect(dead_code)], like #[allow(dead_code)],
+//       except that the item itself is not marked live.
 //   or
 //   2) We are not sure to be live or not
 //     * Implementations of traits and trait methods
 struct LifeSeeder<'k, 'tcx> {
     worklist: Vec<LocalDefId>,
+    // the items expecting `dead_code`, see `KeptAlive::OnlyItsUses`
+    expected_dead_items: FxHashSet<LocalDefId>,
     krate: &'k hir::Crate<'k>,
     tcx: TyCtxt<'tcx>,
     // see `MarkSymbolVisitor::struct_constructors`
     struct_constructors: FxHashMap<LocalDefId, LocalDefId>,
 }
 
+impl LifeSeeder<'_, '_> {
+    fn push_kept_alive(&mut self, def_id: LocalDefId, kept_alive: KeptAlive) {
+        self.worklist.push(def_id);
+        if kept_alive == KeptAlive::OnlyItsUses {
+            self.expected_dead_items.insert(def_id);
+        }
+    }
+}
+
 impl<'v, 'k, 'tcx> ItemLikeVisitor<'v> for LifeSeeder<'k, 'tcx> {
     fn visit_item(&mut self, item: &hir::Item<'_>) {
         let allow_dead_code = has_allow_dead_code_or_lang_attr(self.tcx, item.hir_id());
-        if allow_dead_code {
-            self.worklist.push(item.def_id);
+        if let Some(kept_alive) = allow_dead_code {
+            self.push_kept_alive(item.def_id, kept_alive);
         }
         match item.kind {
             hir::ItemKind::Enum(ref enum_def, _) => {
                 let hir = self.tcx.hir();
-                if allow_dead_code {
-                    self.worklist.extend(
-                        enum_def.variants.iter().map(|variant| hir.local_def_id(variant.id)),
-                    );
+                if let Some(kept_alive) = allow_dead_code {
+                    for variant in enum_def.variants {
+                        self.push_kept_alive(hir.local_def_id(variant.id), kept_alive);
+                    }
                 }
 
                 for variant in enum_def.variants {
@@ -477,10 +596,12 @@ fn visit_item(&mut self, item: &hir::Item<'_>) {
                 }
                 for impl_item_ref in items {
                     let impl_item = self.krate.impl_item(impl_item_ref.id);
-                    if of_trait.is_some()
-                        || has_allow_dead_code_or_lang_attr(self.tcx, impl_item.hir_id())
-                    {
+                    if of_trait.is_some() {
                         self.worklist.push(impl_item_ref.id.def_id);
+                    } else if let Some(kept_alive) =
+                        has_allow_dead_code_or_lang_attr(self.tcx, impl_item.hir_id())
+                    {
+                        self.push_kept_alive(impl_item_ref.id.def_id, kept_alive);
                     }
                 }
             }
@@ -496,10 +617,12 @@ fn visit_item(&mut self, item: &hir::Item<'_>) {
 
     fn visit_trait_item(&mut self, trait_item: &hir::TraitItem<'_>) {
         use hir::TraitItemKind::{Const, Fn};
-        if matches!(trait_item.kind, Const(_, Some(_)) | Fn(_, hir::TraitFn::Provided(_)))
-            && has_allow_dead_code_or_lang_attr(self.tcx, trait_item.hir_id())
-        {
-            self.worklist.push(trait_item.def_id);
+        if matches!(trait_item.kind, Const(_, Some(_)) | Fn(_, hir::TraitFn::Provided(_))) {
+            if let Some(kept_alive) =
+                has_allow_dead_code_or_lang_attr(self.tcx, trait_item.hir_id())
+            {
+                self.push_kept_alive(trait_item.def_id, kept_alive);
+            }
         }
     }
 
@@ -509,10 +632,12 @@ fn visit_impl_item(&mut self, _item: &hir::ImplItem<'_>) {
 
     fn visit_foreign_item(&mut self, foreign_item: &hir::ForeignItem<'_>) {
         use hir::ForeignItemKind::{Fn, Static};
-        if matches!(foreign_item.kind, Static(..) | Fn(..))
-            && has_allow_dead_code_or_lang_attr(self.tcx, foreign_item.hir_id())
-        {
-            self.worklist.push(foreign_item.def_id);
+        if matches!(foreign_item.kind, Static(..) | Fn(..)) {
+            if let Some(kept_alive) =
+                has_allow_dead_code_or_lang_attr(self.tcx, foreign_item.hir_id())
+            {
+                self.push_kept_alive(foreign_item.def_id, kept_alive);
+            }
         }
     }
 }
@@ -521,7 +646,7 @@ fn create_and_seed_worklist<'tcx>(
     tcx: TyCtxt<'tcx>,
     access_levels: &privacy::AccessLevels,
     krate: &hir::Crate<'_>,
-) -> (Vec<LocalDefId>, FxHashMap<LocalDefId, LocalDefId>) {
+) -> (Vec<LocalDefId>, FxHashSet<LocalDefId>, FxHashMap<LocalDefId, LocalDefId>) {
     let worklist = access_levels
         .map
         .iter()
@@ -535,19 +660,29 @@ fn create_and_seed_worklist<'tcx>(
         .collect::<Vec<_>>();
 
     // Seed implemented trait items
-    let mut life_seeder =
-        LifeSeeder { worklist, krate, tcx, struct_constructors: Default::default() };
+    let mut life_seeder = LifeSeeder {
+        worklist,
+        expected_dead_items: Default::default(),
+        krate,
+        tcx,
+        struct_constructors: Default::default(),
+    };
     krate.visit_all_item_likes(&mut life_seeder);
 
-    (life_seeder.worklist, life_seeder.struct_constructors)
+    // Reachable items are live even if they expect `dead_code`.
+    let mut expected_dead_items = life_seeder.expected_dead_items;
+    expected_dead_items.retain(|&def_id| !access_levels.is_reachable(def_id));
+
+    (life_seeder.worklist, expected_dead_items, life_seeder.struct_constructors)
 }
 
 fn find_live<'tcx>(
     tcx: TyCtxt<'tcx>,
     access_levels: &privacy::AccessLevels,
     krate: &hir::Crate<'_>,
-) -> FxHashSet<LocalDefId> {
-    let (worklist, struct_constructors) = create_and_seed_worklist(tcx, access_levels, krate);
+) -> LiveSymbols {
+    let (worklist, expected_dead_items, struct_constructors) =
+        create_and_seed_worklist(tcx, access_levels, krate);
     let mut symbol_visitor = MarkSymbolVisitor {
         worklist,
         tcx,
@@ -559,14 +694,41 @@ fn find_live<'tcx>(
         pub_visibility: false,
         ignore_variant_stack: vec![],
         struct_constructors,
+        skip_derived_impls: true,
+        skipped_derived_impl_items: vec![],
+        ignored_derived_traits: Default::default(),
+        expected_dead_items,
     };
     symbol_visitor.mark_live_symbols();
-    symbol_visitor.live_symbols
+
+    // Derived impls count as uses for `dead_code`, so walk the skipped ones now. Whatever they
+    // make live is only used by derived impls, which `fields_only_read_by_derives` reports.
+    let live_without_derives = symbol_visitor.live_symbols.clone();
+    symbol_visitor.skip_derived_impls = false;
+    symbol_visitor.worklist = std::mem::take(&mut symbol_visitor.skipped_derived_impl_items);
+    symbol_visitor.mark_live_symbols();
+    let derive_only_symbols =
+        symbol_visitor.live_symbols.difference(&live_without_derives).copied().collect();
+
+    LiveSymbols {
+        live_symbols: symbol_visitor.live_symbols,
+        derive_only_symbols,
+        ignored_derived_traits: symbol_visitor.ignored_derived_traits,
+    }
+}
+
+struct LiveSymbols {
+    live_symbols: FxHashSet<LocalDefId>,
+    // symbols that are only live because of derived impls
+    derive_only_symbols: FxHashSet<LocalDefId>,
+    ignored_derived_traits: FxHashMap<LocalDefId, Vec<DefId>>,
 }
 
 struct DeadVisitor<'tcx> {
     tcx: TyCtxt<'tcx>,
     live_symbols: FxHashSet<LocalDefId>,
+    derive_only_symbols: FxHashSet<LocalDefId>,
+    ignored_derived_traits: FxHashMap<LocalDefId, Vec<DefId>>,
 }
 
 impl DeadVisitor<'tcx> {
@@ -584,22 +746,38 @@ fn should_warn_about_item(&mut self, item: &hir::Item<'_>) -> bool {
         should_warn && !self.symbol_is_live(item.def_id)
     }
 
+    fn is_reportable_field(&self, field: &hir::FieldDef<'_>, def_id: LocalDefId) -> bool {
+        let field_type = self.tcx.type_of(def_id);
+        // Positional fields are only reported for tuple structs (and not for tuple variants),
+        // unless they are already of unit type, which is how such warnings are silenced.
+        let is_reported_positional = || {
+            let parent = self.tcx.parent(def_id.to_def_id()).unwrap();
+            self.tcx.def_kind(parent) == DefKind::Struct && !field_type.is_unit()
+        };
+        (!field.is_positional() || is_reported_positional()) && !field_type.is_phantom_data()
+    }
+
     fn should_warn_about_field(&mut self, field: &hir::FieldDef<'_>) -> bool {
         let def_id = self.tcx.hir().local_def_id(field.hir_id);
-        let field_type = self.tcx.type_of(def_id);
-        !field.is_positional()
+        self.is_reportable_field(field, def_id)
             && !self.symbol_is_live(def_id)
-            && !field_type.is_phantom_data()
-            && !has_allow_dead_code_or_lang_attr(self.tcx, field.hir_id)
+            && has_allow_dead_code_or_lang_attr(self.tcx, field.hir_id) != Some(KeptAlive::Always)
+    }
+
+    fn is_field_only_read_by_derives(&self, field: &hir::FieldDef<'_>) -> bool {
+        let def_id = self.tcx.hir().local_def_id(field.hir_id);
+        self.derive_only_symbols.contains(&def_id) && self.is_reportable_field(field, def_id)
     }
 
     fn should_warn_about_variant(&mut self, variant: &hir::Variant<'_>) -> bool {
         let def_id = self.tcx.hir().local_def_id(variant.id);
-        !self.symbol_is_live(def_id) && !has_allow_dead_code_or_lang_attr(self.tcx, variant.id)
+        !self.symbol_is_live(def_id)
+            && has_allow_dead_code_or_lang_attr(self.tcx, variant.id) != Some(KeptAlive::Always)
     }
 
     fn should_warn_about_foreign_item(&mut self, fi: &hir::ForeignItem<'_>) -> bool {
-        !self.symbol_is_live(fi.def_id) && !has_allow_dead_code_or_lang_attr(self.tcx, fi.hir_id())
+        !self.symbol_is_live(fi.def_id)
+            && has_allow_dead_code_or_lang_attr(self.tcx, fi.hir_id()) != Some(KeptAlive::Always)
     }
 
     // id := HIR id of an item's definition.
@@ -639,6 +817,67 @@ fn warn_dead_code(
             });
         }
     }
+
+    fn warn_unused_tuple_struct_field(&mut self, field: &hir::FieldDef<'_>) {
+        self.tcx.struct_span_lint_hir(
+            lint::builtin::UNUSED_TUPLE_STRUCT_FIELDS,
+            field.hir_id,
+            field.span,
+            |lint| {
+                let mut err = lint.build(&format!("field is never read: `{}`", field.ident));
+                err.span_suggestion(
+                    field.ty.span,
+                    "consider changing the field to be of unit type to suppress this warning \
+                     while preserving the field numbering, or remove the field",
+                    "()".to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+                err.emit()
+            },
+        );
+    }
+
+    fn warn_field_only_read_by_derives(&mut self, field: &hir::FieldDef<'_>) {
+        if field.ident.name.as_str().starts_with('_') {
+            return;
+        }
+        self.tcx.struct_span_lint_hir(
+            lint::builtin::FIELDS_ONLY_READ_BY_DERIVES,
+            field.hir_id,
+            field.span,
+            |lint| {
+                let mut err =
+                    lint.build(&format!("field is only read by derived impls: `{}`", field.ident));
+                self.note_ignored_derived_traits(&mut err, field.hir_id);
+                err.emit()
+            },
+        );
+    }
+
+    /// Notes the derived impls whose field reads were ignored for the ADT enclosing `id`.
+    fn note_ignored_derived_traits(&self, err: &mut DiagnosticBuilder<'_>, id: hir::HirId) {
+        let hir = self.tcx.hir();
+        let adt_def_id = hir.local_def_id(hir.get_parent_item(id));
+        let traits = match self.ignored_derived_traits.get(&adt_def_id) {
+            Some(traits) => traits,
+            None => return,
+        };
+        let mut trait_names = traits
+            .iter()
+            .map(|&trait_def_id| format!("`{}`", self.tcx.item_name(trait_def_id)))
+            .collect::<Vec<_>>();
+        trait_names.sort();
+        err.note(&format!(
+            "`{}` has {}derived impl{} for the trait{} {}, but {} not counted as {} of its fields",
+            self.tcx.item_name(adt_def_id.to_def_id()),
+            if traits.len() == 1 { "a " } else { "" },
+            pluralize!(traits.len()),
+            pluralize!(traits.len()),
+            trait_names.join(" and "),
+            if traits.len() == 1 { "this is" } else { "these are" },
+            if traits.len() == 1 { "a read" } else { "reads" },
+        ));
+    }
 }
 
 impl Visitor<'tcx> for DeadVisitor<'tcx> {
@@ -710,7 +949,13 @@ fn visit_foreign_item(&mut self, fi: &'tcx hir::ForeignItem<'tcx>) {
 
     fn visit_field_def(&mut self, field: &'tcx hir::FieldDef<'tcx>) {
         if self.should_warn_about_field(&field) {
-            self.warn_dead_code(field.hir_id, field.span, field.ident.name, "read");
+            if field.is_positional() {
+                self.warn_unused_tuple_struct_field(field);
+            } else {
+                self.warn_dead_code(field.hir_id, field.span, field.ident.name, "read");
+            }
+        } else if self.is_field_only_read_by_derives(&field) {
+            self.warn_field_only_read_by_derives(field);
         }
         intravisit::walk_field_def(self, field);
     }
@@ -766,7 +1011,9 @@ fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem<'tcx>) {
 pub fn check_crate(tcx: TyCtxt<'_>) {
     let access_levels = &tcx.privacy_access_levels(());
     let krate = tcx.hir().krate();
-    let live_symbols = find_live(tcx, access_levels, krate);
-    let mut visitor = DeadVisitor { tcx, live_symbols };
+    let LiveSymbols { live_symbols, derive_only_symbols, ignored_derived_traits } =
+        find_live(tcx, access_levels, krate);
+    let mut visitor =
+        DeadVisitor { tcx, live_symbols, derive_only_symbols, ignored_derived_traits };
     intravisit::walk_crate(&mut visitor, krate);
 }
@@ -238,6 +238,17 @@ fn alloc_self_profile_query_strings_for_query_cache<'tcx, C>(
     tcx.prof.with_profiler(|profiler| {
         let event_id_builder = profiler.event_id_builder();
 
+        // Events kept in memory for `-Z self-profile-format=chrome` and
+        // `-Z self-profile-summarize` are grouped by query name only.
+        if profiler.event_recording_enabled() {
+            let mut query_invocation_ids = Vec::new();
+            query_cache.iter_results(&mut |_, _, i| query_invocation_ids.push(i.into()));
+            profiler.map_query_invocation_ids_to_query_name(
+                query_invocation_ids.into_iter(),
+                query_name,
+            );
+        }
+
         // Walk the entire query cache and allocate the appropriate
         // string representations. Each cache entry is uniquely
         // identified by its dep_node_index.
@@ -1,7 +1,9 @@
+use rustc_data_structures::profiling::TimePassesFormat;
 use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_middle::ty::query::query_storage;
 use rustc_middle::ty::TyCtxt;
 use rustc_query_system::query::{QueryCache, QueryCacheStore};
+use rustc_serialize::json::{self, Json, ToJson};
 
 use std::any::type_name;
 use std::mem;
@@ -63,6 +65,11 @@ fn stats<C>(name: &'static str, map: &QueryCacheStore<C>) -> QueryStats
 pub fn print_stats(tcx: TyCtxt<'_>) {
     let queries = query_stats(tcx);
 
+    if tcx.sess.opts.debugging_opts.time_passes_format == TimePassesFormat::Json {
+        print_json_stats(&queries);
+        return;
+    }
+
     if cfg!(debug_assertions) {
         let hits: usize = queries.iter().map(|s| s.cache_hits).sum();
         let results: usize = queries.iter().map(|s| s.entry_count).sum();
@@ -115,6 +122,24 @@ pub fn print_stats(tcx: TyCtxt<'_>) {
     }
 }
 
+/// Prints one JSON object per query. `cache_hits` is only tracked with debug assertions and is
+/// `null` otherwise.
+fn print_json_stats(queries: &[QueryStats]) {
+    for q in queries {
+        let mut obj = json::Object::new();
+        obj.insert("query".to_string(), q.name.to_json());
+        obj.insert("entry_count".to_string(), q.entry_count.to_json());
+        let cache_hits = if cfg!(debug_assertions) { Some(q.cache_hits) } else { None };
+        obj.insert("cache_hits".to_string(), cache_hits.to_json());
+        obj.insert("key_size".to_string(), q.key_size.to_json());
+        obj.insert("key_type".to_string(), q.key_type.to_json());
+        obj.insert("value_size".to_string(), q.value_size.to_json());
+        obj.insert("value_type".to_string(), q.value_type.to_json());
+        obj.insert("local_def_id_keys".to_string(), q.local_def_id_keys.to_json());
+        eprintln!("{}", Json::Object(obj));
+    }
+}
+
 macro_rules! print_stats {
     (<$tcx:tt>
         $($(#[$attr:meta])* [$($modifiers:tt)*] fn $name:ident($K:ty) -> $V:ty,)*
@@ -12,19 +12,25 @@
 use crate::query::{QueryContext, QueryMap, QuerySideEffects, QueryStackFrame};
 
 use rustc_data_structures::fingerprint::Fingerprint;
-use rustc_data_structures::fx::{FxHashMap, FxHasher};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxHasher};
 use rustc_data_structures::sharded::{get_shard_index_by_hash, Sharded};
 use rustc_data_structures::sync::{Lock, LockGuard};
 use rustc_data_structures::thin_vec::ThinVec;
 #[cfg(not(parallel_compiler))]
 use rustc_errors::DiagnosticBuilder;
 use rustc_errors::{Diagnostic, FatalError};
+use rustc_session::config::IncrementalVerifyIch;
+use rustc_session::Session;
+use rustc_span::source_map::SourceMap;
 use rustc_span::{Span, DUMMY_SP};
 use std::collections::hash_map::Entry;
 use std::fmt::Debug;
+use std::fs::{self, File};
 use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
 use std::mem;
 use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
 use std::ptr;
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -521,7 +527,7 @@ fn try_execute_query<CTX, C>(
     result
 }
 
-fn load_from_disk_and_cache_in_memory<CTX, K, V: Debug>(
+fn load_from_disk_and_cache_in_memory<CTX, K: Clone + Debug, V: Debug>(
     tcx: CTX,
     key: K,
     prev_dep_node_index: SerializedDepNodeIndex,
@@ -560,8 +566,19 @@ fn load_from_disk_and_cache_in_memory<CTX, K, V: Debug>(
     if let Some(result) = result {
         // If `-Zincremental-verify-ich` is specified, re-hash results from
         // the cache and make sure that they have the expected fingerprint.
-        if unlikely!(tcx.dep_context().sess().opts.debugging_opts.incremental_verify_ich) {
-            incremental_verify_ich(*tcx.dep_context(), &result, dep_node, query);
+        if unlikely!(tcx.dep_context().sess().opts.debugging_opts.incremental_verify_ich.is_some())
+        {
+            // The result is the one of the previous session, there is nothing to compare it with:
+            // recomputing it would only show how the query changed, not why its hash did.
+            incremental_verify_ich(
+                *tcx.dep_context(),
+                &key,
+                &result,
+                true,
+                dep_node,
+                query,
+                || None,
+            );
         }
 
         result
@@ -571,7 +588,8 @@ fn load_from_disk_and_cache_in_memory<CTX, K, V: Debug>(
         let prof_timer = tcx.dep_context().profiler().query_provider();
 
         // The dep-graph for this computation is already in-place.
-        let result = tcx.dep_context().dep_graph().with_ignore(|| compute(*tcx.dep_context(), key));
+        let result =
+            tcx.dep_context().dep_graph().with_ignore(|| compute(*tcx.dep_context(), key.clone()));
 
         prof_timer.finish_with_query_invocation_id(dep_node_index.into());
 
@@ -584,17 +602,32 @@ fn load_from_disk_and_cache_in_memory<CTX, K, V: Debug>(
         //
         // See issue #82920 for an example of a miscompilation that would get turned into
         // an ICE by this check
-        incremental_verify_ich(*tcx.dep_context(), &result, dep_node, query);
+        incremental_verify_ich(*tcx.dep_context(), &key, &result, false, dep_node, query, || {
+            // Only used for the report of `-Zincremental-verify-ich=dump`.
+            if query.cache_on_disk(tcx, &key, Some(&result)) {
+                query.try_load_from_disk(tcx, prev_dep_node_index)
+            } else {
+                None
+            }
+        });
 
         result
     }
 }
 
-fn incremental_verify_ich<CTX, K, V: Debug>(
+/// Re-hashes `result` and ICEs if the hash doesn't match the fingerprint from the previous
+/// session. `loaded_from_disk` tells whether `result` comes from the on-disk cache (and thus from
+/// the previous session) or was recomputed in this session, in which case `previous` tries to
+/// load the result of the previous session for the report written by
+/// `-Zincremental-verify-ich=dump`.
+fn incremental_verify_ich<CTX, K: Debug, V: Debug>(
     tcx: CTX::DepContext,
+    key: &K,
     result: &V,
+    loaded_from_disk: bool,
     dep_node: &DepNode<CTX::DepKind>,
     query: &QueryVtable<CTX, K, V>,
+    previous: impl FnOnce() -> Option<V>,
 ) where
     CTX: QueryContext,
 {
@@ -607,26 +640,223 @@ fn incremental_verify_ich<CTX, K, V: Debug>(
     debug!("BEGIN verify_ich({:?})", dep_node);
     let mut hcx = tcx.create_stable_hashing_context();
 
-    let new_hash = query.hash_result(&mut hcx, result).unwrap_or(Fingerprint::ZERO);
+    let mut new_hash = query.hash_result(&mut hcx, result).unwrap_or(Fingerprint::ZERO);
+    if tcx.sess().opts.debugging_opts.incremental_verify_ich_force_mismatch {
+        new_hash = new_hash.combine(Fingerprint::new(0, 1));
+    }
     debug!("END verify_ich({:?})", dep_node);
 
     let old_hash = tcx.dep_graph().prev_fingerprint_of(dep_node);
 
     if Some(new_hash) != old_hash {
+        let report = if let Some(IncrementalVerifyIch::Dump(dir)) =
+            &tcx.sess().opts.debugging_opts.incremental_verify_ich
+        {
+            let report = UnstableFingerprintReport {
+                dep_node,
+                key,
+                old_hash,
+                new_hash,
+                result: format!("{:#?}", result),
+                previous: previous().map(|v| format!("{:#?}", v)),
+                loaded_from_disk,
+            };
+            match report.write(tcx.sess(), dir.as_deref()) {
+                Ok(path) => Some(path),
+                Err(err) => {
+                    tcx.sess().warn(&format!(
+                        "failed to write a report for the unstable fingerprint: {}",
+                        err
+                    ));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         let run_cmd = if let Some(crate_name) = &tcx.sess().opts.crate_name {
             format!("`cargo clean -p {}` or `cargo clean`", crate_name)
         } else {
             "`cargo clean`".to_string()
         };
-        tcx.sess().struct_err(&format!("internal compiler error: encountered incremental compilation error with {:?}", dep_node))
-            .help(&format!("This is a known issue with the compiler. Run {} to allow your project to compile", run_cmd))
+        let mut diag = tcx.sess().struct_err(&format!(
+            "internal compiler error: encountered incremental compilation error with {:?}",
+            dep_node
+        ));
+        diag.help(&format!("This is a known issue with the compiler. Run {} to allow your project to compile", run_cmd))
             .note(&"Please follow the instructions below to create a bug report with the provided information")
-            .note(&"See <https://github.com/rust-lang/rust/issues/84970> for more information")
-            .emit();
+            .note(&"See <https://github.com/rust-lang/rust/issues/84970> for more information");
+        match report {
+            Some(path) => {
+                diag.note(&format!(
+                    "A report for this error was written to `{}`, please attach it to the bug \
+                    report",
+                    path.display()
+                ));
+            }
+            None => {
+                diag.note(
+                    "Run with `-Zincremental-verify-ich=dump` to write a report that can be \
+                    attached to the bug report",
+                );
+            }
+        }
+        diag.emit();
         panic!("Found unstable fingerprints for {:?}: {:?}", dep_node, result);
     }
 }
 
+/// The report written by `-Zincremental-verify-ich=dump` when a query result hashes differently
+/// than in the previous session.
+struct UnstableFingerprintReport<'a, K, DK> {
+    dep_node: &'a DepNode<DK>,
+    key: &'a K,
+    old_hash: Option<Fingerprint>,
+    new_hash: Fingerprint,
+    /// The `Debug` output of the result that was hashed.
+    result: String,
+    /// The `Debug` output of the result from the previous session, if `result` was recomputed in
+    /// this session and the previous one could be loaded from the on-disk cache.
+    previous: Option<String>,
+    loaded_from_disk: bool,
+}
+
+impl<K: Debug, DK: DepKind> UnstableFingerprintReport<'_, K, DK> {
+    /// Writes the report to `dir`, or to the `ich_reports` directory of the incremental compilation
+    /// directory, and returns its path. The session directory is not used, as it is deleted when
+    /// the compilation fails.
+    fn write(&self, sess: &Session, dir: Option<&Path>) -> io::Result<PathBuf> {
+        let dir = match dir {
+            Some(dir) => dir.to_path_buf(),
+            None => sess.opts.incremental.as_deref().unwrap_or(Path::new(".")).join("ich_reports"),
+        };
+        fs::create_dir_all(&dir)?;
+        let crate_name = sess.opts.crate_name.as_deref().unwrap_or("unknown");
+        let path = dir.join(format!(
+            "{}-{:?}-{}.txt",
+            crate_name,
+            self.dep_node.kind,
+            self.new_hash.to_hex()
+        ));
+
+        let mut file = io::BufWriter::new(File::create(&path)?);
+        self.write_to(&mut file, sess.source_map())?;
+        file.flush()?;
+        Ok(path)
+    }
+
+    fn write_to(&self, w: &mut impl Write, source_map: &SourceMap) -> io::Result<()> {
+        writeln!(w, "unstable fingerprint for {:?}", self.dep_node)?;
+        writeln!(w)?;
+        writeln!(w, "query key: {:?}", self.key)?;
+        match self.old_hash {
+            Some(old_hash) => writeln!(w, "previous fingerprint: {}", old_hash.to_hex())?,
+            None => writeln!(w, "previous fingerprint: <none>")?,
+        }
+        writeln!(w, "current fingerprint: {}", self.new_hash.to_hex())?;
+
+        writeln!(w)?;
+        if self.loaded_from_disk {
+            // There is no other result to compare with, recomputing it could only show how the
+            // query changed while its inputs did not.
+            writeln!(
+                w,
+                "note: the result was loaded from the on-disk cache and hashed again, so both \
+                fingerprints come from the same value: its hashing is not stable across sessions"
+            )?;
+            writeln!(w)?;
+            writeln!(w, "--- result (loaded from the on-disk cache) ---")?;
+            writeln!(w, "{}", self.result)?;
+            return Ok(());
+        }
+
+        match &self.previous {
+            Some(previous) => {
+                writeln!(w, "--- previous result (loaded from the on-disk cache) ---")?;
+                writeln!(w, "{}", previous)?;
+            }
+            None => writeln!(w, "--- previous result (not available) ---")?,
+        }
+        writeln!(w)?;
+        writeln!(w, "--- current result (recomputed) ---")?;
+        writeln!(w, "{}", self.result)?;
+
+        let (previous, current) = match &self.previous {
+            Some(previous) => (previous, &self.result),
+            None => return Ok(()),
+        };
+
+        // Only print the lines that differ, the common prefix and suffix are usually huge.
+        let previous: Vec<_> = previous.lines().collect();
+        let current: Vec<_> = current.lines().collect();
+        let prefix = previous.iter().zip(&current).take_while(|(a, b)| a == b).count();
+        let suffix = previous[prefix..]
+            .iter()
+            .rev()
+            .zip(current[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let previous = &previous[prefix..previous.len() - suffix];
+        let current = &current[prefix..current.len() - suffix];
+
+        writeln!(w)?;
+        writeln!(w, "--- differences (starting at line {}) ---", prefix + 1)?;
+        for line in previous {
+            writeln!(w, "-{}", line)?;
+        }
+        for line in current {
+            writeln!(w, "+{}", line)?;
+        }
+
+        // `Span`s are printed as `file.rs:1:2: 3:4 (#0)`, look up the source code of the ones
+        // that are part of the differences.
+        let mut spans = FxHashSet::default();
+        for line in previous.iter().chain(current) {
+            for (end, _) in line.match_indices(" (#") {
+                if let Some(span) = parse_debug_span(&line[..end]) {
+                    spans.insert(span);
+                }
+            }
+        }
+        if spans.is_empty() {
+            return Ok(());
+        }
+
+        let mut spans: Vec<_> = spans.into_iter().collect();
+        spans.sort_unstable();
+        writeln!(w)?;
+        writeln!(w, "--- source spans ---")?;
+        for (span, file_name, lo_line, hi_line) in spans {
+            writeln!(w, "{}", span)?;
+            let file = source_map
+                .files()
+                .iter()
+                .find(|file| file.name.prefer_local().to_string() == file_name)
+                .cloned();
+            if let Some(file) = file {
+                for line in lo_line..=hi_line.min(lo_line + 4) {
+                    if let Some(source) = line.checked_sub(1).and_then(|line| file.get_line(line)) {
+                        writeln!(w, "{:>5} | {}", line, source)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the `Span` printed with the source map (`file.rs:1:2: 3:4`) at the end of `s`, returning
+/// the span, its file name and its first and last line.
+fn parse_debug_span(s: &str) -> Option<(&str, &str, usize, usize)> {
+    let (lo, hi) = s.rsplit_once(": ")?;
+    let (hi_line, _) = hi.split_once(':')?;
+    let (lo, _) = lo.rsplit_once(':')?;
+    let (file_name, lo_line) = lo.rsplit_once(':')?;
+    let start = file_name.rfind(|c: char| c.is_whitespace() || c == '(').map_or(0, |i| i + 1);
+    Some((&s[start..], &file_name[start..], lo_line.parse().ok()?, hi_line.parse().ok()?))
+}
+
 fn force_query_with_job<C, CTX>(
     tcx: CTX,
     key: C::Key,
@@ -961,6 +961,8 @@ pub struct Resolver<'a> {
     crate_loader: CrateLoader<'a>,
     macro_names: FxHashSet<Ident>,
     builtin_macros: FxHashMap<Symbol, BuiltinMacroState>,
+    /// Expands `#[cfg]` attributes with `accessible(path)` predicates, created on first use.
+    deferred_cfg_ext: Option<Lrc<SyntaxExtension>>,
     registered_attrs: FxHashSet<Ident>,
     registered_tools: FxHashSet<Ident>,
     macro_use_prelude: FxHashMap<Symbol, &'a NameBinding<'a>>,
@@ -1354,6 +1356,7 @@ pub fn new(
             crate_loader: CrateLoader::new(session, metadata_loader, crate_name),
             macro_names: FxHashSet::default(),
             builtin_macros: Default::default(),
+            deferred_cfg_ext: None,
             registered_attrs,
             registered_tools,
             macro_use_prelude: FxHashMap::default(),
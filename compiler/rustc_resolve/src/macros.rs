@@ -267,6 +267,26 @@ fn resolve_macro_invocation(
             }
         };
 
+        // The only `#[cfg]` attributes collected as invocations are the ones using `accessible(path)`
+        // predicates. They are expanded by a built-in that is not looked up by name, so other
+        // macros in scope cannot shadow it.
+        if let InvocationKind::Attr { ref attr, .. } = invoc.kind {
+            if attr.has_name(sym::cfg) {
+                let ext = self.deferred_cfg_ext();
+                invoc_id.set_expn_data(
+                    ext.expn_data(
+                        parent_scope.expansion,
+                        invoc.span(),
+                        fast_print_path(&attr.get_normal_item().path),
+                        None,
+                        None,
+                    ),
+                    self.create_stable_hashing_context(),
+                );
+                return Ok(ext);
+            }
+        }
+
         let (path, kind, inner_attr, derives) = match invoc.kind {
             InvocationKind::Attr { ref attr, ref derives, .. } => (
                 &attr.get_normal_item().path,
@@ -425,7 +445,8 @@ fn take_derive_resolutions(&mut self, expn_id: LocalExpnId) -> Option<DeriveReso
 
     // The function that implements the resolution logic of `#[cfg_accessible(path)]`.
     // Returns true if the path can certainly be resolved in one of three namespaces,
-    // returns false if the path certainly cannot be resolved in any of the three namespaces.
+    // returns false if the path certainly cannot be resolved in any of the three namespaces,
+    // e.g. because it doesn't exist or is private.
     // Returns `Indeterminate` if we cannot give a certain answer yet.
     fn cfg_accessible(
         &mut self,
@@ -437,6 +458,7 @@ fn cfg_accessible(
         let parent_scope = self.invocation_parent_scopes[&expn_id];
 
         let mut indeterminate = false;
+        let mut partially_resolved = false;
         for ns in [TypeNS, ValueNS, MacroNS].iter().copied() {
             match self.resolve_path(path, Some(ns), &parent_scope, false, span, CrateLint::No) {
                 PathResult::Module(ModuleOrUniformRoot::Module(_)) => return Ok(true),
@@ -445,9 +467,12 @@ fn cfg_accessible(
                 }
                 PathResult::Indeterminate => indeterminate = true,
                 // FIXME: `resolve_path` is not ready to report partially resolved paths
-                // correctly, so we just report an error if the path was reported as unresolved.
-                // This needs to be fixed for `cfg_accessible` to be useful.
-                PathResult::NonModule(..) | PathResult::Failed { .. } => {}
+                // correctly, e.g. associated items and fields are not resolved here, so we
+                // just report an error if the path was only resolved up to a type.
+                PathResult::NonModule(..) => partially_resolved = true,
+                // The path certainly cannot be resolved from here, either because some segment
+                // doesn't exist or because it is not accessible.
+                PathResult::Failed { .. } => {}
                 PathResult::Module(_) => panic!("unexpected path resolution"),
             }
         }
@@ -456,6 +481,10 @@ fn cfg_accessible(
             return Err(Indeterminate);
         }
 
+        if !partially_resolved {
+            return Ok(false);
+        }
+
         self.session
             .struct_span_err(span, "not sure whether the path is accessible or not")
             .span_note(span, "`cfg_accessible` is not fully implemented")
@@ -1206,6 +1235,21 @@ fn prohibit_imported_non_macro_attrs(
         }
     }
 
+    fn deferred_cfg_ext(&mut self) -> Lrc<SyntaxExtension> {
+        if let Some(ext) = &self.deferred_cfg_ext {
+            return ext.clone();
+        }
+        let kind = match self.builtin_macros.remove(&sym::deferred_cfg) {
+            Some(BuiltinMacroState::NotYetSeen(kind)) => kind,
+            _ => self.session.diagnostic().bug("built-in macro `deferred_cfg` was not registered"),
+        };
+        let mut ext = SyntaxExtension::default(kind, self.session.edition());
+        ext.builtin_name = Some(sym::cfg);
+        let ext = Lrc::new(ext);
+        self.deferred_cfg_ext = Some(ext.clone());
+        ext
+    }
+
     /// Compile the macro into a `SyntaxExtension` and possibly replace
     /// its expander to a pre-defined one for built-in macros.
     crate fn compile_macro(&mut self, item: &ast::Item, edition: Edition) -> SyntaxExtension {
@@ -8,7 +8,7 @@
 use crate::utils::{CanonicalizedPath, NativeLib, NativeLibKind};
 use crate::{early_error, early_warn, Session};
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::impl_stable_hash_via_hash;
 
 use rustc_target::abi::{Align, TargetDataLayout};
@@ -16,7 +16,7 @@
 
 use rustc_serialize::json;
 
-use crate::parse::CrateConfig;
+use crate::parse::{CrateCheckConfig, CrateConfig};
 use rustc_feature::UnstableFeatures;
 use rustc_span::edition::{Edition, DEFAULT_EDITION, EDITION_NAME_LIST, LATEST_STABLE_EDITION};
 use rustc_span::source_map::{FileName, FilePathMapping};
@@ -127,6 +127,17 @@ pub enum MirSpanview {
     Block,
 }
 
+/// The different settings that the `-Z incremental-verify-ich` flag can have.
+#[derive(Clone, PartialEq, Hash, Debug)]
+pub enum IncrementalVerifyIch {
+    /// Default `-Z incremental-verify-ich` or `-Z incremental-verify-ich=yes`
+    Verify,
+    /// `-Z incremental-verify-ich=dump[=<dir>]`: in addition to verifying, write a report for
+    /// each unstable fingerprint to `<dir>`, or to the `ich_reports` directory of the incremental
+    /// compilation directory.
+    Dump(Option<PathBuf>),
+}
+
 /// The different settings that the `-Z instrument-coverage` flag can have.
 ///
 /// Coverage instrumentation now supports combining `-Z instrument-coverage`
@@ -189,6 +200,24 @@ pub fn enabled(&self) -> bool {
     }
 }
 
+/// The format of the `-Z dump-mono-stats` report.
+#[derive(Copy, Clone, PartialEq, Hash, Debug)]
+pub enum DumpMonoStatsFormat {
+    /// A human-readable Markdown table.
+    Markdown,
+    /// Machine-readable JSON.
+    Json,
+}
+
+impl DumpMonoStatsFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            DumpMonoStatsFormat::Markdown => "md",
+            DumpMonoStatsFormat::Json => "json",
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[derive(Encodable, Decodable)]
 pub enum SymbolManglingVersion {
@@ -897,6 +926,100 @@ pub fn build_configuration(sess: &Session, mut user_cfg: CrateConfig) -> CrateCo
     user_cfg
 }
 
+/// The values a cfg name is expected to be used with, as given by `--check-cfg`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedValues<T> {
+    /// Only the listed values are expected. `None` stands for the name being used
+    /// without a value, as in `#[cfg(name)]`.
+    Some(FxHashSet<Option<T>>),
+    /// Any value (or no value at all) is expected.
+    Any,
+}
+
+/// The parsed `--check-cfg` options.
+#[derive(Clone, Debug)]
+pub struct CheckCfg<T = String> {
+    /// Whether cfg names not present in `expecteds` should be reported. This is set
+    /// as soon as `--check-cfg` is passed at all.
+    pub exhaustive_names: bool,
+    /// The expected values of every cfg name that was declared.
+    pub expecteds: FxHashMap<T, ExpectedValues<T>>,
+}
+
+impl<T> Default for CheckCfg<T> {
+    fn default() -> Self {
+        CheckCfg { exhaustive_names: false, expecteds: FxHashMap::default() }
+    }
+}
+
+impl<T> CheckCfg<T> {
+    fn map_data<O: Eq + Hash>(self, f: impl Fn(T) -> O) -> CheckCfg<O> {
+        CheckCfg {
+            exhaustive_names: self.exhaustive_names,
+            expecteds: self
+                .expecteds
+                .into_iter()
+                .map(|(name, values)| {
+                    let values = match values {
+                        ExpectedValues::Some(values) => ExpectedValues::Some(
+                            values.into_iter().map(|value| value.map(&f)).collect(),
+                        ),
+                        ExpectedValues::Any => ExpectedValues::Any,
+                    };
+                    (f(name), values)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts the crate `--check-cfg` options from `String` to `Symbol`.
+/// `rustc_interface::interface::Config` accepts this in the compiler configuration,
+/// but the symbol interner is not yet set up then, so we must convert it later.
+pub fn to_crate_check_config(cfg: CheckCfg) -> CrateCheckConfig {
+    cfg.map_data(|s| Symbol::intern(&s))
+}
+
+impl CrateCheckConfig {
+    /// Adds the cfg names the compiler itself may set, so that `--check-cfg` does not
+    /// need to list them. Their values are left unchecked, since they vary between
+    /// targets and sessions.
+    pub fn fill_well_known(&mut self) {
+        if !self.exhaustive_names {
+            return;
+        }
+
+        const WELL_KNOWN_NAMES: &[Symbol] = &[
+            sym::debug_assertions,
+            sym::doc,
+            sym::doctest,
+            sym::miri,
+            sym::panic,
+            sym::proc_macro,
+            sym::sanitize,
+            sym::target_abi,
+            sym::target_arch,
+            sym::target_endian,
+            sym::target_env,
+            sym::target_family,
+            sym::target_feature,
+            sym::target_has_atomic,
+            sym::target_has_atomic_equal_alignment,
+            sym::target_has_atomic_load_store,
+            sym::target_os,
+            sym::target_pointer_width,
+            sym::target_thread_local,
+            sym::target_vendor,
+            sym::test,
+            sym::unix,
+            sym::windows,
+        ];
+        for &name in WELL_KNOWN_NAMES {
+            self.expecteds.entry(name).or_insert(ExpectedValues::Any);
+        }
+    }
+}
+
 pub(super) fn build_target_config(
     opts: &Options,
     target_override: Option<Target>,
@@ -1028,6 +1151,7 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
     vec![
         opt::flag_s("h", "help", "Display this message"),
         opt::multi_s("", "cfg", "Configure the compilation environment", "SPEC"),
+        opt::multi("", "check-cfg", "Provide list of valid cfg options for checking", "SPEC"),
         opt::multi_s(
             "L",
             "",
@@ -8,6 +8,7 @@
 use rustc_target::spec::{CodeModel, LinkerFlavor, MergeFunctions, PanicStrategy, SanitizerSet};
 use rustc_target::spec::{RelocModel, RelroLevel, SplitDebuginfo, TargetTriple, TlsModel};
 
+use rustc_data_structures::profiling::{SelfProfileFormat, TimePassesFormat};
 use rustc_feature::UnstableFeatures;
 use rustc_span::edition::Edition;
 use rustc_span::RealFileName;
@@ -359,6 +360,8 @@ mod desc {
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavor::one_of();
     pub const parse_optimization_fuel: &str = "crate=integer";
     pub const parse_mir_spanview: &str = "`statement` (default), `terminator`, or `block`";
+    pub const parse_incremental_verify_ich: &str =
+        "one of: `y`, `yes`, `on`, `n`, `no`, `off`, `dump`, or `dump=<dir>`";
     pub const parse_instrument_coverage: &str =
         "`all` (default), `except-unused-generics`, `except-unused-functions`, or `off`";
     pub const parse_unpretty: &str = "`string` or `string=string`";
@@ -372,6 +375,8 @@ mod desc {
     pub const parse_merge_functions: &str = "one of: `disabled`, `trampolines`, or `aliases`";
     pub const parse_symbol_mangling_version: &str = "either `legacy` or `v0` (RFC 2603)";
     pub const parse_src_file_hash: &str = "either `md5` or `sha1`";
+    pub const parse_self_profile_format: &str = "either `raw` or `chrome`";
+    pub const parse_time_passes_format: &str = "either `text` or `json`";
     pub const parse_relocation_model: &str =
         "one of supported relocation models (`rustc --print relocation-models`)";
     pub const parse_code_model: &str = "one of supported code models (`rustc --print code-models`)";
@@ -381,6 +386,7 @@ mod desc {
     pub const parse_split_debuginfo: &str =
         "one of supported split-debuginfo modes (`off`, `packed`, or `unpacked`)";
     pub const parse_gcc_ld: &str = "one of: no value, `lld`";
+    pub const parse_dump_mono_stats: &str = "`markdown` (default) or `json`";
 }
 
 mod parse {
@@ -697,6 +703,29 @@ mod parse {
         true
     }
 
+    crate fn parse_incremental_verify_ich(
+        slot: &mut Option<IncrementalVerifyIch>,
+        v: Option<&str>,
+    ) -> bool {
+        if v.is_some() {
+            let mut bool_arg = None;
+            if parse_opt_bool(&mut bool_arg, v) {
+                *slot = if bool_arg.unwrap() { Some(IncrementalVerifyIch::Verify) } else { None };
+                return true;
+            }
+        }
+
+        *slot = match v {
+            None => Some(IncrementalVerifyIch::Verify),
+            Some("dump") => Some(IncrementalVerifyIch::Dump(None)),
+            Some(v) => match v.strip_prefix("dump=") {
+                Some(dir) if !dir.is_empty() => Some(IncrementalVerifyIch::Dump(Some(dir.into()))),
+                _ => return false,
+            },
+        };
+        true
+    }
+
     crate fn parse_instrument_coverage(
         slot: &mut Option<InstrumentCoverage>,
         v: Option<&str>,
@@ -846,6 +875,24 @@ mod parse {
         true
     }
 
+    crate fn parse_self_profile_format(slot: &mut SelfProfileFormat, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("raw") => SelfProfileFormat::Raw,
+            Some("chrome") => SelfProfileFormat::Chrome,
+            _ => return false,
+        };
+        true
+    }
+
+    crate fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("text") => TimePassesFormat::Text,
+            Some("json") => TimePassesFormat::Json,
+            _ => return false,
+        };
+        true
+    }
+
     crate fn parse_target_feature(slot: &mut String, v: Option<&str>) -> bool {
         match v {
             Some(s) => {
@@ -884,6 +931,15 @@ mod parse {
         }
         true
     }
+
+    crate fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&str>) -> bool {
+        match v {
+            None | Some("markdown") => *slot = DumpMonoStatsFormat::Markdown,
+            Some("json") => *slot = DumpMonoStatsFormat::Json,
+            _ => return false,
+        }
+        true
+    }
 }
 
 options! {
@@ -1037,6 +1093,9 @@ mod parse {
     dep_info_omit_d_target: bool = (false, parse_bool, [TRACKED],
         "in dep-info output, omit targets for tracking dependencies of the dep-info files \
         themselves (default: no)"),
+    dep_graph_filter: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "only include the dep-nodes of these comma-separated kinds in the output of \
+        `-Z dump-dep-graph`, e.g. `typeck,optimized_mir` (default: all kinds)"),
     dep_tasks: bool = (false, parse_bool, [UNTRACKED],
         "print tasks that execute and the color their dep node gets (requires debug build) \
         (default: no)"),
@@ -1046,8 +1105,8 @@ mod parse {
     dual_proc_macros: bool = (false, parse_bool, [TRACKED],
         "load proc macros for both target and host, but only link to the target (default: no)"),
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
-        "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
-        (default: no)"),
+        "dump the dependency graph to $RUST_DEP_GRAPH.{txt,dot,json} (default: dep_graph), \
+        colored by the red/green marking results (default: no)"),
     dump_mir: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "dump MIR state to file.
         `val` is used to select which passes and functions to dump. For example:
@@ -1072,6 +1131,13 @@ mod parse {
         computed `block` spans (one span encompassing a block's terminator and \
         all statements). If `-Z instrument-coverage` is also enabled, create \
         an additional `.html` file showing the computed coverage spans."),
+    dump_mono_stats: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output statistics about monomorphization collection to the given directory \
+        (default: the output directory)"),
+    dump_mono_stats_format: DumpMonoStatsFormat = (DumpMonoStatsFormat::Markdown,
+        parse_dump_mono_stats, [UNTRACKED],
+        "the format to use for -Z dump-mono-stats (`markdown` (default) or `json`)"),
     emit_future_incompat_report: bool = (false, parse_bool, [UNTRACKED],
         "emits a future-incompatibility report for lints (RFC 2834)"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
@@ -1106,8 +1172,13 @@ mod parse {
     incremental_info: bool = (false, parse_bool, [UNTRACKED],
         "print high-level information about incremental reuse (or the lack thereof) \
         (default: no)"),
-    incremental_verify_ich: bool = (false, parse_bool, [UNTRACKED],
-        "verify incr. comp. hashes of green query instances (default: no)"),
+    incremental_verify_ich: Option<IncrementalVerifyIch> = (None, parse_incremental_verify_ich, [UNTRACKED],
+        "verify incr. comp. hashes of green query instances; `dump[=<dir>]` also writes a \
+        report for each unstable fingerprint to `<dir>`, or to the `ich_reports` directory of \
+        the incremental compilation directory (default: no)"),
+    incremental_verify_ich_force_mismatch: bool = (false, parse_bool, [UNTRACKED],
+        "treat the fingerprints of all the verified query results as unstable -- used for \
+        testing (default: no)"),
     inline_mir: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "enable MIR inlining (default: no)"),
     inline_mir_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
@@ -1259,6 +1330,13 @@ mod parse {
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, incr-result-hashing, query-keys, function-args, args, llvm"),
+    self_profile_format: SelfProfileFormat = (SelfProfileFormat::Raw,
+        parse_self_profile_format, [UNTRACKED],
+        "the format of the self profiler output: `raw` event data only, or additionally \
+        a Chrome `trace_event` JSON file (default: raw)"),
+    self_profile_summarize: bool = (false, parse_bool, [UNTRACKED],
+        "print a summary of the self profiler data, with the top items by self time \
+        and per-query cache-hit ratios, when the compilation session ends (default: no)"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     show_span: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -1300,6 +1378,9 @@ mod parse {
         "measure time of each LLVM pass (default: no)"),
     time_passes: bool = (false, parse_bool, [UNTRACKED],
         "measure time of each rustc pass (default: no)"),
+    time_passes_format: TimePassesFormat = (TimePassesFormat::Text, parse_time_passes_format, [UNTRACKED],
+        "the format of the `-Z time-passes` and `-Z query-stats` output: human readable \
+        `text`, or `json` with one object per line (default: text)"),
     tls_model: Option<TlsModel> = (None, parse_tls_model, [TRACKED],
         "choose the TLS model to use (`rustc --print tls-models` for details)"),
     trace_macros: bool = (false, parse_bool, [UNTRACKED],
@@ -354,6 +354,9 @@ pub fn struct_span_allow<S: Into<MultiSpan>>(&self, sp: S, msg: &str) -> Diagnos
     pub fn struct_allow(&self, msg: &str) -> DiagnosticBuilder<'_> {
         self.diagnostic().struct_allow(msg)
     }
+    pub fn struct_expect(&self, msg: &str, expectation: Span) -> DiagnosticBuilder<'_> {
+        self.diagnostic().struct_expect(msg, expectation)
+    }
     pub fn struct_span_err<S: Into<MultiSpan>>(&self, sp: S, msg: &str) -> DiagnosticBuilder<'_> {
         self.diagnostic().struct_span_err(sp, msg)
     }
@@ -1265,6 +1268,8 @@ pub fn build_session(
             directory,
             sopts.crate_name.as_deref(),
             &sopts.debugging_opts.self_profile_events,
+            sopts.debugging_opts.self_profile_format,
+            sopts.debugging_opts.self_profile_summarize,
         );
         match profiler {
             Ok(profiler) => Some(Arc::new(profiler)),
@@ -1312,6 +1317,7 @@ pub fn build_session(
         self_profiler,
         sopts.debugging_opts.time_passes || sopts.debugging_opts.time,
         sopts.debugging_opts.time_passes,
+        sopts.debugging_opts.time_passes_format,
     );
 
     let ctfe_backtrace = Lock::new(match env::var("RUSTC_CTFE_BACKTRACE") {
@@ -280,6 +280,7 @@
         abi_x86_interrupt,
         abort,
         aborts,
+        accessible,
         add,
         add_assign,
         add_with_overflow,
@@ -379,6 +380,7 @@
         call_once,
         caller_location,
         capture_disjoint_fields,
+        cast,
         cdylib,
         ceilf32,
         ceilf64,
@@ -513,6 +515,7 @@
         default_method_body_is_const,
         default_type_parameter_fallback,
         default_type_params,
+        deferred_cfg,
         delay_span_bug_from_inside_query,
         deny,
         deprecated,
@@ -777,6 +780,7 @@
         macro_export,
         macro_lifetime_matcher,
         macro_literal_matcher,
+        macro_metavar_expr,
         macro_reexport,
         macro_use,
         macro_vis_matcher,
@@ -819,6 +823,7 @@
         minnumf32,
         minnumf64,
         mips_target_feature,
+        miri,
         misc,
         mmx_reg,
         modifiers,
@@ -880,6 +885,7 @@
         non_ascii_idents,
         non_exhaustive,
         non_modrs_mods,
+        none,
         none_error,
         nontemporal_store,
         noop_method_borrow,
@@ -989,6 +995,10 @@
         ptr_null,
         ptr_null_mut,
         ptr_offset_from,
+        ptr_replace,
+        ptr_write,
+        ptr_write_unaligned,
+        ptr_write_volatile,
         pub_macro_rules,
         pub_restricted,
         pure,
@@ -1033,6 +1043,7 @@
         relaxed_struct_unsize,
         rem,
         rem_assign,
+        replace,
         repr,
         repr128,
         repr_align,
@@ -1130,6 +1141,7 @@
         rustc_synthetic,
         rustc_test_marker,
         rustc_then_this_would_need,
+        rustc_trivial_field_reads,
         rustc_unsafe_specialization_marker,
         rustc_variance,
         rustdoc,
@@ -1252,6 +1264,7 @@
         store,
         str,
         str_alloc,
+        strict_provenance,
         string_type,
         stringify,
         struct_field_attributes,
@@ -1385,6 +1398,7 @@
         va_list,
         va_start,
         val,
+        values,
         var,
         variant_count,
         vec,
@@ -1413,7 +1427,10 @@
         wrapping_mul,
         wrapping_sub,
         wreg,
+        write,
         write_bytes,
+        write_unaligned,
+        write_volatile,
         x87_reg,
         xmm_reg,
         ymm_reg,
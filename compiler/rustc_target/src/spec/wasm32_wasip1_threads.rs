@@ -0,0 +1,70 @@
+//! The `wasm32-wasip1-threads` target is an extension of the `wasm32-wasi`
+//! target where threads are enabled by default for all crates. This target
+//! should be considered "in flux" as WASI itself has yet to have its threading
+//! proposal standardized.
+//!
+//! Threads are spawned through the `thread-spawn` host function described by
+//! the [wasi-threads] proposal, which `wasi-libc` wraps in its `pthread`
+//! implementation. Compared to `wasm32-wasi` this target:
+//!
+//! * enables the `atomics`, `bulk-memory` and `mutable-globals` features,
+//! * links against the `wasm32-wasi-threads` flavor of `wasi-libc`,
+//! * imports a shared linear memory instead of defining its own, so the same
+//!   memory can be handed to every instance that runs a thread.
+//!
+//! [wasi-threads]: https://github.com/WebAssembly/wasi-threads
+
+use super::wasm_base;
+use super::{crt_objects, LinkerFlavor, LldFlavor, Target};
+
+pub fn target() -> Target {
+    let mut options = wasm_base::options();
+
+    options.os = "wasi".to_string();
+    options.linker_flavor = LinkerFlavor::Lld(LldFlavor::Wasm);
+
+    // When linking with `wasm-ld` directly the `--shared-memory` and
+    // `--import-memory` flags are implied by the `atomics` feature, see
+    // `WasmLd::new`. When going through `clang` we need to pass them ourselves.
+    options.pre_link_args.entry(LinkerFlavor::Gcc).or_insert(Vec::new()).extend(
+        [
+            "--target=wasm32-wasi-threads",
+            "-Wl,--import-memory",
+            "-Wl,--shared-memory",
+            "-Wl,--max-memory=1073741824",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+
+    options.pre_link_objects_fallback = crt_objects::pre_wasi_fallback();
+    options.post_link_objects_fallback = crt_objects::post_wasi_fallback();
+
+    // Right now this is a bit of a workaround but we're currently saying that
+    // the target by default has a static crt which we're taking as a signal
+    // for "use the bundled crt". If that's turned off then the system's crt
+    // will be used, but this means that default usage of this target doesn't
+    // need an external compiler but it's still interoperable with an external
+    // compiler if configured correctly.
+    options.crt_static_default = true;
+    options.crt_static_respected = true;
+
+    // Allow `+crt-static` to create a "cdylib" output which is just a wasm file
+    // without a main function.
+    options.crt_static_allows_dylibs = true;
+
+    // WASI's `sys::args::init` function ignores its arguments; instead,
+    // `args::args()` makes the WASI API calls itself.
+    options.main_needs_argc_argv = false;
+
+    options.singlethread = false;
+    options.features = "+atomics,+bulk-memory,+mutable-globals".to_string();
+
+    Target {
+        llvm_target: "wasm32-wasi".to_string(),
+        pointer_width: 32,
+        data_layout: "e-m:e-p:32:32-i64:64-n32:64-S128".to_string(),
+        arch: "wasm32".to_string(),
+        options,
+    }
+}
@@ -26,6 +26,10 @@
 use std::iter;
 use std::slice;
 
+/// The largest number of arguments for which we look for a reordering of the supplied arguments
+/// that would make them all type-check.
+const MAX_PERMUTED_ARGS: usize = 6;
+
 impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
     pub(in super::super) fn check_casts(&self) {
         let mut deferred_cast_checks = self.deferred_cast_checks.borrow_mut();
@@ -306,6 +310,15 @@ pub(in super::super) fn check_argument_types(
 
         let mut final_arg_types: Vec<(usize, Ty<'_>, Ty<'_>)> = vec![];
 
+        // When the arguments might just be in the wrong order, hold on to the coercion errors
+        // until every argument has been checked, so they can be replaced by a single suggestion
+        // to reorder them.
+        let detect_permutation = !c_variadic
+            && tuple_arguments == DontTupleArguments
+            && (2..=MAX_PERMUTED_ARGS).contains(&supplied_arg_count)
+            && expected_arg_count == supplied_arg_count;
+        let mut coercion_errors: Vec<DiagnosticBuilder<'_>> = vec![];
+
         // Check the arguments.
         // We do this in a pretty awful way: first we type-check any arguments
         // that are not closures, then we type-check the closures. This is so
@@ -368,7 +381,15 @@ pub(in super::super) fn check_argument_types(
                 let coerce_ty = expected.only_has_type(self).unwrap_or(formal_ty);
                 // We're processing function arguments so we definitely want to use
                 // two-phase borrows.
-                self.demand_coerce(&arg, checked_ty, coerce_ty, None, AllowTwoPhase::Yes);
+                let (_, coerce_error) =
+                    self.demand_coerce_diag(&arg, checked_ty, coerce_ty, None, AllowTwoPhase::Yes);
+                if let Some(mut err) = coerce_error {
+                    if detect_permutation {
+                        coercion_errors.push(err);
+                    } else {
+                        err.emit();
+                    }
+                }
                 final_arg_types.push((i, checked_ty, coerce_ty));
 
                 // 3. Relate the expected type and the formal one,
@@ -377,6 +398,19 @@ pub(in super::super) fn check_argument_types(
             }
         }
 
+        if !coercion_errors.is_empty() {
+            if let Some(permutation) = self.find_argument_permutation(&final_arg_types) {
+                for mut err in coercion_errors {
+                    err.cancel();
+                }
+                self.report_permuted_arguments(expr, args, &final_arg_types, &permutation, def_id);
+            } else {
+                for mut err in coercion_errors {
+                    err.emit();
+                }
+            }
+        }
+
         // We also need to make sure we at least write the ty of the other
         // arguments which we skipped above.
         if c_variadic {
@@ -413,6 +447,147 @@ fn variadic_error<'tcx>(sess: &Session, span: Span, ty: Ty<'tcx>, cast_ty: &str)
         }
     }
 
+    /// Looks for an order of the supplied arguments in which each of them can be coerced to the
+    /// type of the parameter it is passed to. `arg_types` holds, for each argument index, the
+    /// type of the supplied argument and the type it was coerced to.
+    ///
+    /// Returns `permutation`, where `permutation[i]` is the index of the supplied argument that
+    /// should be passed as the `i`th argument, if it differs from the order that was written.
+    fn find_argument_permutation(
+        &self,
+        arg_types: &[(usize, Ty<'tcx>, Ty<'tcx>)],
+    ) -> Option<Vec<usize>> {
+        let mut checked_tys = vec![None; arg_types.len()];
+        let mut expected_tys = vec![None; arg_types.len()];
+        for &(i, checked_ty, coerce_ty) in arg_types {
+            checked_tys[i] = Some(self.resolve_vars_if_possible(checked_ty));
+            expected_tys[i] = Some(self.resolve_vars_if_possible(coerce_ty));
+        }
+        let checked_tys: Vec<_> = checked_tys.into_iter().collect::<Option<_>>()?;
+        let expected_tys: Vec<_> = expected_tys.into_iter().collect::<Option<_>>()?;
+        if checked_tys.iter().chain(&expected_tys).any(|ty| ty.references_error()) {
+            return None;
+        }
+
+        // `compatible[arg][param]`: whether the supplied argument `arg` could be passed as `param`.
+        let compatible: Vec<Vec<bool>> = checked_tys
+            .iter()
+            .map(|&checked_ty| {
+                expected_tys
+                    .iter()
+                    .map(|&expected_ty| self.can_coerce(checked_ty, expected_ty))
+                    .collect()
+            })
+            .collect();
+
+        fn assign(
+            compatible: &[Vec<bool>],
+            param: usize,
+            used: &mut Vec<bool>,
+            permutation: &mut Vec<usize>,
+        ) -> bool {
+            if param == compatible.len() {
+                return true;
+            }
+            // Prefer leaving arguments where they are, so that the suggestion moves as few of
+            // them as possible.
+            let candidates =
+                iter::once(param).chain((0..compatible.len()).filter(|&arg| arg != param));
+            for arg in candidates {
+                if !used[arg] && compatible[arg][param] {
+                    used[arg] = true;
+                    permutation.push(arg);
+                    if assign(compatible, param + 1, used, permutation) {
+                        return true;
+                    }
+                    permutation.pop();
+                    used[arg] = false;
+                }
+            }
+            false
+        }
+
+        let mut used = vec![false; compatible.len()];
+        let mut permutation = Vec::with_capacity(compatible.len());
+        if !assign(&compatible, 0, &mut used, &mut permutation) {
+            return None;
+        }
+        if permutation.iter().enumerate().all(|(param, &arg)| param == arg) {
+            return None;
+        }
+        Some(permutation)
+    }
+
+    /// Reports arguments whose types would all match if they were passed in a different order,
+    /// suggesting the reordered call.
+    fn report_permuted_arguments(
+        &self,
+        expr: &'tcx hir::Expr<'tcx>,
+        args: &'tcx [hir::Expr<'tcx>],
+        arg_types: &[(usize, Ty<'tcx>, Ty<'tcx>)],
+        permutation: &[usize],
+        def_id: Option<DefId>,
+    ) {
+        let tcx = self.tcx;
+        let (call_span, descr) = match expr.kind {
+            hir::ExprKind::MethodCall(_, span, ..) => (span, "method"),
+            hir::ExprKind::Call(callee, _) => (callee.span, "function"),
+            _ => (expr.span, "function"),
+        };
+        let mut err = struct_span_err!(
+            tcx.sess,
+            call_span,
+            E0308,
+            "arguments to this {} are passed in the wrong order",
+            descr,
+        );
+
+        let mut types = vec![None; args.len()];
+        for &(i, checked_ty, coerce_ty) in arg_types {
+            types[i] = Some((
+                self.resolve_vars_if_possible(checked_ty),
+                self.resolve_vars_if_possible(coerce_ty),
+            ));
+        }
+        let source_map = tcx.sess.source_map();
+        let mut suggestions = Some(vec![]);
+        for (param, &arg) in permutation.iter().enumerate() {
+            if param == arg {
+                continue;
+            }
+            if let Some((found, expected)) = types[param] {
+                err.span_label(
+                    args[param].span,
+                    format!("expected `{}`, found `{}`", expected, found),
+                );
+            }
+            // Only suggest the reordering if all of the arguments can be moved around verbatim.
+            suggestions = suggestions.and_then(|mut suggestions| {
+                let snippet = source_map.span_to_snippet(args[arg].span).ok()?;
+                suggestions.push((args[param].span, snippet));
+                Some(suggestions)
+            });
+        }
+
+        if let Some(def_id) = def_id {
+            if let Some(def_span) = tcx.def_ident_span(def_id) {
+                let def_kind = tcx.def_kind(def_id);
+                err.span_note(def_span, &format!("{} defined here", def_kind.descr(def_id)));
+            }
+        }
+
+        if let Some(suggestions) = suggestions {
+            let msg = if suggestions.len() == 2 {
+                "swap these arguments"
+            } else {
+                "reorder these arguments"
+            };
+            // Moving arguments around changes the order in which they are evaluated.
+            err.multipart_suggestion(msg, suggestions, Applicability::MaybeIncorrect);
+        }
+        err.emit();
+    }
+
     // AST fragment checking
     pub(in super::super) fn check_lit(
         &self,
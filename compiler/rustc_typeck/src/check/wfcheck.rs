@@ -12,6 +12,7 @@
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::ItemKind;
 use rustc_middle::hir::map as hir_map;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts, Subst};
 use rustc_middle::ty::trait_def::TraitSpecializationKind;
 use rustc_middle::ty::{
@@ -19,7 +20,7 @@
 };
 use rustc_session::parse::feature_err;
 use rustc_span::symbol::{sym, Ident, Symbol};
-use rustc_span::Span;
+use rustc_span::{BytePos, Span};
 use rustc_trait_selection::opaque_types::may_define_opaque_type;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
 use rustc_trait_selection::traits::{self, ObligationCause, ObligationCauseCode, WellFormedLoc};
@@ -1334,38 +1335,296 @@ fn check_variances_for_type_defn<'tcx>(
 
         match param.name {
             hir::ParamName::Error => {}
-            _ => report_bivariance(tcx, param),
+            _ => report_bivariance(tcx, item, hir_generics, index),
         }
     }
 }
 
-fn report_bivariance(tcx: TyCtxt<'_>, param: &rustc_hir::GenericParam<'_>) {
+fn report_bivariance(
+    tcx: TyCtxt<'_>,
+    item: &hir::Item<'_>,
+    hir_generics: &hir::Generics<'_>,
+    param_index: usize,
+) {
+    let param = &hir_generics.params[param_index];
     let span = param.span;
     let param_name = param.name.ident().name;
     let mut err = error_392(tcx, span, param_name);
 
+    let (removal, removal_applicability) =
+        generic_param_removal_suggestion(tcx, hir_generics, param_index);
     let suggested_marker_id = tcx.lang_items().phantom_data();
     // Help is available only in presence of lang items.
-    let msg = if let Some(def_id) = suggested_marker_id {
-        format!(
-            "consider removing `{}`, referring to it in a field, or using a marker such as `{}`",
+    if let Some(def_id) = suggested_marker_id {
+        err.multipart_suggestion(
+            &format!("consider removing `{}`", param_name),
+            removal,
+            removal_applicability,
+        );
+
+        let marker_path = with_no_trimmed_paths(|| tcx.def_path_str(def_id));
+        let marker_ty = match param.kind {
+            hir::GenericParamKind::Lifetime { .. } => {
+                format!("{}<&{} ()>", marker_path, param_name)
+            }
+            _ => format!("{}<{}>", marker_path, param_name),
+        };
+        let msg = format!(
+            "consider referring to `{}` through a `{}` marker",
             param_name,
             tcx.def_path_str(def_id),
-        )
+        );
+        // Adding a field or variant breaks the places constructing or matching on the type.
+        match phantom_data_suggestion(tcx, item, hir_generics, &marker_ty) {
+            Some((sugg_span, sugg)) => {
+                err.span_suggestion_verbose(sugg_span, &msg, sugg, Applicability::MaybeIncorrect);
+            }
+            None => {
+                err.help(&msg);
+            }
+        }
     } else {
-        format!("consider removing `{}` or referring to it in a field", param_name)
-    };
-    err.help(&msg);
+        err.multipart_suggestion(
+            &format!("consider removing `{}` or referring to it in a field", param_name),
+            removal,
+            removal_applicability,
+        );
+    }
 
-    if matches!(param.kind, rustc_hir::GenericParamKind::Type { .. }) {
-        err.help(&format!(
-            "if you intended `{0}` to be a const parameter, use `const {0}: usize` instead",
-            param_name
-        ));
+    if matches!(param.kind, hir::GenericParamKind::Type { .. }) {
+        err.span_suggestion_verbose(
+            generic_param_full_span(hir_generics, param),
+            &format!(
+                "if you intended `{0}` to be a const parameter, use `const {0}: usize` instead",
+                param_name
+            ),
+            format!("const {}: usize", param_name),
+            Applicability::MaybeIncorrect,
+        );
     }
     err.emit()
 }
 
+/// Returns the span of `param` including its inline bounds and default, but not any bounds
+/// that were written in the `where` clause.
+fn generic_param_full_span(generics: &hir::Generics<'_>, param: &hir::GenericParam<'_>) -> Span {
+    let mut span = param.span;
+    for bound in param.bounds {
+        if generics.span.contains(bound.span()) {
+            span = span.to(bound.span());
+        }
+    }
+    if let hir::GenericParamKind::Type { default: Some(ty), .. } = param.kind {
+        span = span.to(ty.span);
+    }
+    span
+}
+
+/// Returns the span to remove in order to drop the parameter at `index` from `generics`,
+/// taking care of the separating comma, or the whole `<...>` list if it is the only one.
+fn generic_param_removal_span(tcx: TyCtxt<'_>, generics: &hir::Generics<'_>, index: usize) -> Span {
+    let source_map = tcx.sess.source_map();
+    let params = generics.params;
+    let full_span = generic_param_full_span(generics, &params[index]);
+    if params.len() == 1 {
+        return generics.span;
+    }
+
+    if index > 0 {
+        // `<A, B, T>` -> `<A, B>`: remove the preceding comma along with the parameter.
+        if let Ok(prev) = source_map.span_to_snippet(generics.span.with_hi(full_span.lo())) {
+            if let Some(comma) = prev.rfind(',') {
+                return full_span.with_lo(generics.span.lo() + BytePos(comma as u32));
+            }
+        }
+    } else {
+        // `<T, A, B>` -> `<A, B>`: remove the following comma and whitespace.
+        if let Ok(next) =
+            source_map.span_to_snippet(full_span.between(generics.span.shrink_to_hi()))
+        {
+            if let Some(comma) = next.find(',') {
+                let rest = &next[comma + 1..];
+                let len = comma + 1 + rest.len() - rest.trim_start().len();
+                return full_span.with_hi(full_span.hi() + BytePos(len as u32));
+            }
+        }
+    }
+    full_span
+}
+
+/// Returns the parts of a suggestion removing the parameter at `index` from `generics` along
+/// with the `where` clause predicates bounding it. The suggestion is machine applicable unless
+/// the parameter is still mentioned by the remaining parameters or predicates.
+fn generic_param_removal_suggestion(
+    tcx: TyCtxt<'_>,
+    generics: &hir::Generics<'_>,
+    index: usize,
+) -> (Vec<(Span, String)>, Applicability) {
+    let source_map = tcx.sess.source_map();
+    let param = &generics.params[index];
+    let mut parts = vec![(generic_param_removal_span(tcx, generics, index), String::new())];
+
+    let predicates = generics.where_clause.predicates;
+    let removed: Vec<usize> = (0..predicates.len())
+        .filter(|&i| where_predicate_bounds_param(tcx, &predicates[i], param))
+        .collect();
+    match (0..predicates.len()).rev().find(|i| !removed.contains(i)) {
+        None if !removed.is_empty() => {
+            // `struct S<T> where T: Copy { .. }` -> `struct S { .. }`: drop the whole clause,
+            // along with the whitespace in front of it.
+            let where_span = generics.where_clause.span;
+            let prev = source_map.span_to_prev_source(where_span).unwrap_or_default();
+            let whitespace = prev.len() - prev.trim_end().len();
+            let lo = where_span.lo() - BytePos(whitespace as u32);
+            parts.push((where_span.with_lo(lo), String::new()));
+        }
+        None => {}
+        Some(last_kept) => {
+            // `where T: Copy, U: Copy` -> `where U: Copy`
+            for &i in removed.iter().filter(|&&i| i < last_kept) {
+                parts.push((predicates[i].span().until(predicates[i + 1].span()), String::new()));
+            }
+            // `where U: Copy, T: Copy` -> `where U: Copy`
+            if let Some(&last) = removed.last().filter(|&&i| i > last_kept) {
+                let span = predicates[last_kept].span().shrink_to_hi().to(predicates[last].span());
+                parts.push((span, String::new()));
+            }
+        }
+    }
+
+    let mut finder = GenericParamFinder {
+        def_id: tcx.hir().local_def_id(param.hir_id).to_def_id(),
+        name: param.name.normalize_to_macros_2_0(),
+        found: false,
+    };
+    for (i, other) in generics.params.iter().enumerate() {
+        if i != index {
+            finder.visit_generic_param(other);
+        }
+    }
+    for (i, predicate) in predicates.iter().enumerate() {
+        if !removed.contains(&i) {
+            finder.visit_where_predicate(predicate);
+        }
+    }
+    let applicability =
+        if finder.found { Applicability::MaybeIncorrect } else { Applicability::MachineApplicable };
+    (parts, applicability)
+}
+
+/// Returns whether `predicate` bounds `param` itself, as in `T: Copy` or `'a: 'b`.
+fn where_predicate_bounds_param(
+    tcx: TyCtxt<'_>,
+    predicate: &hir::WherePredicate<'_>,
+    param: &hir::GenericParam<'_>,
+) -> bool {
+    match (predicate, &param.kind) {
+        (hir::WherePredicate::BoundPredicate(p), hir::GenericParamKind::Type { .. }) => {
+            match p.bounded_ty.kind {
+                hir::TyKind::Path(hir::QPath::Resolved(None, path)) => {
+                    path.res.opt_def_id() == Some(tcx.hir().local_def_id(param.hir_id).to_def_id())
+                }
+                _ => false,
+            }
+        }
+        (hir::WherePredicate::RegionPredicate(p), hir::GenericParamKind::Lifetime { .. }) => {
+            p.lifetime.name.normalize_to_macros_2_0()
+                == hir::LifetimeName::Param(param.name.normalize_to_macros_2_0())
+        }
+        _ => false,
+    }
+}
+
+/// Looks for mentions of the generic parameter `def_id`, named `name`.
+struct GenericParamFinder {
+    def_id: DefId,
+    name: hir::ParamName,
+    found: bool,
+}
+
+impl<'v> Visitor<'v> for GenericParamFinder {
+    type Map = hir_visit::ErasedMap<'v>;
+
+    fn nested_visit_map(&mut self) -> hir_visit::NestedVisitorMap<Self::Map> {
+        hir_visit::NestedVisitorMap::None
+    }
+
+    fn visit_path(&mut self, path: &'v hir::Path<'v>, _: hir::HirId) {
+        self.found |= path.res.opt_def_id() == Some(self.def_id);
+        hir_visit::walk_path(self, path);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'v hir::Lifetime) {
+        self.found |=
+            lifetime.name.normalize_to_macros_2_0() == hir::LifetimeName::Param(self.name);
+    }
+}
+
+/// Returns a span and snippet inserting `marker_ty` into the definition of `item`, as a new
+/// field for structs and unions and as a new variant for enums.
+fn phantom_data_suggestion(
+    tcx: TyCtxt<'_>,
+    item: &hir::Item<'_>,
+    generics: &hir::Generics<'_>,
+    marker_ty: &str,
+) -> Option<(Span, String)> {
+    let source_map = tcx.sess.source_map();
+    // Whatever is between the braces of a `struct S { }`, `union U { }` or `enum E { }`.
+    let empty_braces = || {
+        let before_closing = item.span.until(source_map.end_point(item.span));
+        let snippet = source_map.span_to_snippet(before_closing).ok()?;
+        let open = snippet.rfind('{')?;
+        Some(before_closing.with_lo(before_closing.lo() + BytePos(open as u32 + 1)))
+    };
+
+    let fresh_name = |base: &str, taken: &[Symbol]| {
+        let mut name = base.to_string();
+        let mut i = 1;
+        while taken.iter().any(|sym| sym.as_str() == name) {
+            name = format!("{}{}", base, i);
+            i += 1;
+        }
+        name
+    };
+
+    match item.kind {
+        ItemKind::Struct(hir::VariantData::Struct(fields, _), _)
+        | ItemKind::Union(hir::VariantData::Struct(fields, _), _) => {
+            let taken: Vec<_> = fields.iter().map(|field| field.ident.name).collect();
+            let field = format!("{}: {}", fresh_name("_marker", &taken), marker_ty);
+            Some(match fields.last() {
+                Some(last) => (last.span.shrink_to_hi(), format!(", {}", field)),
+                None => (empty_braces()?, format!(" {} ", field)),
+            })
+        }
+        ItemKind::Struct(hir::VariantData::Tuple(fields, _), _) => match fields.last() {
+            Some(last) => Some((last.span.shrink_to_hi(), format!(", {}", marker_ty))),
+            None => {
+                // `struct S<T>();`: the opening parenthesis directly follows the generics, as the
+                // `where` clause of tuple structs is written after the fields.
+                let after_generics = generics.span.between(item.span.shrink_to_hi());
+                let snippet = source_map.span_to_snippet(after_generics).ok()?;
+                let paren = snippet.find('(')?;
+                let pos = after_generics.lo() + BytePos(paren as u32 + 1);
+                Some((after_generics.with_lo(pos).with_hi(pos), marker_ty.to_string()))
+            }
+        },
+        ItemKind::Struct(hir::VariantData::Unit(_), _) => {
+            Some((generics.span.shrink_to_hi(), format!("({})", marker_ty)))
+        }
+        // A variant with fields cannot be added next to explicit discriminants.
+        ItemKind::Enum(ref def, _) if def.variants.iter().all(|v| v.disr_expr.is_none()) => {
+            let taken: Vec<_> = def.variants.iter().map(|variant| variant.ident.name).collect();
+            let variant = format!("{}({})", fresh_name("Marker", &taken), marker_ty);
+            Some(match def.variants.last() {
+                Some(last) => (last.span.shrink_to_hi(), format!(", {}", variant)),
+                None => (empty_braces()?, format!(" {} ", variant)),
+            })
+        }
+        _ => None,
+    }
+}
+
 /// Feature gates RFC 2056 -- trivial bounds, checking for global bounds that
 /// aren't true.
 fn check_false_global_bounds(fcx: &FnCtxt<'_, '_>, span: Span, id: hir::HirId) {
@@ -235,6 +235,38 @@ pub const fn null_mut<T>() -> *mut T {
     0 as *mut T
 }
 
+/// Converts an address back to a pointer, picking up a previously "exposed" provenance.
+///
+/// This is equivalent to `addr as *const T`. The provenance of the returned pointer is that of
+/// *any* pointer that was previously passed to [`expose_addr`][pointer::expose_addr]. Code that
+/// has a pointer with the right provenance at hand should prefer
+/// [`with_addr`][pointer::with_addr] instead.
+#[must_use]
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+pub fn from_exposed_addr<T>(addr: usize) -> *const T
+where
+    T: Sized,
+{
+    addr as *const T
+}
+
+/// Converts an address back to a mutable pointer, picking up a previously "exposed" provenance.
+///
+/// This is equivalent to `addr as *mut T`. The provenance of the returned pointer is that of
+/// *any* pointer that was previously passed to [`expose_addr`][pointer::expose_addr]. Code that
+/// has a pointer with the right provenance at hand should prefer
+/// [`with_addr`][pointer::with_addr] instead.
+#[must_use]
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+pub fn from_exposed_addr_mut<T>(addr: usize) -> *mut T
+where
+    T: Sized,
+{
+    addr as *mut T
+}
+
 /// Forms a raw slice from a pointer and a length.
 ///
 /// The `len` argument is the number of **elements**, not the number of bytes.
@@ -565,6 +597,7 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_replace", issue = "83164")]
+#[rustc_diagnostic_item = "ptr_replace"]
 pub const unsafe fn replace<T>(dst: *mut T, mut src: T) -> T {
     // SAFETY: the caller must guarantee that `dst` is valid to be
     // cast to a mutable reference (valid for writes, aligned, initialized),
@@ -879,6 +912,7 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+#[rustc_diagnostic_item = "ptr_write"]
 pub const unsafe fn write<T>(dst: *mut T, src: T) {
     // We are calling the intrinsics directly to avoid function calls in the generated code
     // as `intrinsics::copy_nonoverlapping` is a wrapper function.
@@ -976,6 +1010,7 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 #[inline]
 #[stable(feature = "ptr_unaligned", since = "1.17.0")]
 #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+#[rustc_diagnostic_item = "ptr_write_unaligned"]
 pub const unsafe fn write_unaligned<T>(dst: *mut T, src: T) {
     // SAFETY: the caller must guarantee that `dst` is valid for writes.
     // `dst` cannot overlap `src` because the caller has mutable access
@@ -1120,6 +1155,7 @@ pub unsafe fn read_volatile<T>(src: *const T) -> T {
 /// ```
 #[inline]
 #[stable(feature = "volatile", since = "1.9.0")]
+#[rustc_diagnostic_item = "ptr_write_volatile"]
 pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
     if cfg!(debug_assertions) && !is_aligned_and_not_null(dst) {
         // Not panicking to keep codegen impact smaller.
@@ -47,6 +47,73 @@ pub const fn cast<U>(self) -> *mut U {
         self as _
     }
 
+    /// Gets the "address" portion of the pointer.
+    ///
+    /// This is similar to `self as usize`, except that the [provenance] of the
+    /// pointer is discarded and not [exposed]. This means that casting the
+    /// returned address back to a pointer yields an invalid pointer that is
+    /// unsound to dereference. To get a usable pointer for a different address,
+    /// use [`with_addr`] instead.
+    ///
+    /// [provenance]: https://github.com/rust-lang/rust/issues/95228
+    /// [exposed]: #method.expose_addr
+    /// [`with_addr`]: #method.with_addr
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn addr(self) -> usize
+    where
+        T: Sized,
+    {
+        // SAFETY: Pointer-to-integer transmutes are valid (if you are okay with losing the
+        // provenance).
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Gets the "address" portion of the pointer, and "exposes" the provenance
+    /// part for future use in [`from_exposed_addr_mut`].
+    ///
+    /// This is equivalent to `self as usize`, which semantically discards
+    /// provenance information. Code that needs to round-trip a pointer through
+    /// an integer should prefer [`addr`] and [`with_addr`] where possible.
+    ///
+    /// [`from_exposed_addr_mut`]: from_exposed_addr_mut
+    /// [`addr`]: #method.addr
+    /// [`with_addr`]: #method.with_addr
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn expose_addr(self) -> usize
+    where
+        T: Sized,
+    {
+        self as usize
+    }
+
+    /// Creates a new pointer with the given address.
+    ///
+    /// This performs the same operation as an `addr as ptr` cast, but copies
+    /// the provenance of `self` to the new pointer. This allows us to
+    /// dynamically preserve and propagate this important information in a way
+    /// that is otherwise impossible with a unary cast.
+    ///
+    /// This is equivalent to using [`wrapping_offset`][pointer::wrapping_offset] to offset
+    /// `self` to the given address, and therefore has all the same capabilities and restrictions.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn with_addr(self, addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        let self_addr = self.addr() as isize;
+        let dest_addr = addr as isize;
+        let offset = dest_addr.wrapping_sub(self_addr);
+
+        // This is the canonical desugaring of this operation.
+        self.cast::<u8>().wrapping_offset(offset).cast::<T>()
+    }
+
     /// Decompose a (possibly wide) pointer into its address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts_mut`].
@@ -660,3 +660,27 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[test]
+fn ptr_with_addr() {
+    let arr = [1u16, 2, 3, 4];
+    let ptr = arr.as_ptr();
+    let third = ptr.with_addr(ptr.addr() + 2 * core::mem::size_of::<u16>());
+    assert_eq!(third, ptr.wrapping_add(2));
+    assert_eq!(unsafe { *third }, 3);
+
+    let mut arr = [1u16, 2, 3, 4];
+    let ptr = arr.as_mut_ptr();
+    let second = ptr.with_addr(ptr.addr() + core::mem::size_of::<u16>());
+    unsafe { *second = 20 };
+    assert_eq!(arr, [1, 20, 3, 4]);
+}
+
+#[test]
+fn ptr_expose_addr_round_trip() {
+    let x = 42u32;
+    let ptr = &x as *const u32;
+    let addr = ptr.expose_addr();
+    assert_eq!(addr, ptr.addr());
+    assert_eq!(ptr::from_exposed_addr::<u32>(addr), ptr);
+}
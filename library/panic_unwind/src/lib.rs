@@ -6,7 +6,8 @@
 //!
 //! 1. MSVC targets use SEH in the `seh.rs` file.
 //! 2. Emscripten uses C++ exceptions in the `emcc.rs` file.
-//! 3. All other targets use libunwind/libgcc in the `gcc.rs` file.
+//! 3. Other wasm targets use native wasm exceptions in the `wasm.rs` file.
+//! 4. All other targets use libunwind/libgcc in the `gcc.rs` file.
 //!
 //! More documentation about each implementation can be found in the respective
 //! module.
@@ -25,6 +26,7 @@
 #![panic_runtime]
 #![feature(panic_runtime)]
 #![feature(c_unwind)]
+#![feature(cfg_panic)]
 // `real_imp` is unused with Miri, so silence warnings.
 #![cfg_attr(miri, allow(dead_code))]
 
@@ -53,9 +55,12 @@
         pub use real_imp::eh_frame_registry::*;
         #[path = "gcc.rs"]
         mod real_imp;
+    } else if #[cfg(all(target_family = "wasm", panic = "unwind"))] {
+        #[path = "wasm.rs"]
+        mod real_imp;
     } else {
         // Targets that don't support unwinding.
-        // - arch=wasm32
+        // - arch=wasm32 (unless built with `-Cpanic=unwind`)
         // - os=none ("bare metal" targets)
         // - os=uefi
         // - os=espidf
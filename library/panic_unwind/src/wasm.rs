@@ -0,0 +1,67 @@
+//! Unwinding for wasm targets using the exception-handling proposal.
+//!
+//! Panics are raised with the `throw` instruction through the `unwind` crate's
+//! shim, pretending to be a C++ exception. `catch_unwind` is compiled to a
+//! catch-all `catchpad`, so no personality routine is ever consulted; the
+//! exception class is used to tell Rust panics apart from foreign exceptions.
+
+use alloc::boxed::Box;
+use core::any::Any;
+
+use unwind as uw;
+
+#[repr(C)]
+struct Exception {
+    _uwe: uw::_Unwind_Exception,
+    cause: Box<dyn Any + Send>,
+}
+
+pub unsafe fn panic(data: Box<dyn Any + Send>) -> u32 {
+    let exception = Box::new(Exception {
+        _uwe: uw::_Unwind_Exception {
+            exception_class: rust_exception_class(),
+            exception_cleanup,
+            private: [core::ptr::null(); uw::unwinder_private_data_size],
+        },
+        cause: data,
+    });
+    let exception_param = Box::into_raw(exception) as *mut uw::_Unwind_Exception;
+    return uw::_Unwind_RaiseException(exception_param) as u32;
+
+    extern "C" fn exception_cleanup(
+        _unwind_code: uw::_Unwind_Reason_Code,
+        exception: *mut uw::_Unwind_Exception,
+    ) {
+        unsafe {
+            let _: Box<Exception> = Box::from_raw(exception as *mut Exception);
+            super::__rust_drop_panic();
+        }
+    }
+}
+
+pub unsafe fn cleanup(ptr: *mut u8) -> Box<dyn Any + Send> {
+    let exception = ptr as *mut uw::_Unwind_Exception;
+    if (*exception).exception_class != rust_exception_class() {
+        uw::_Unwind_DeleteException(exception);
+        super::__rust_foreign_exception();
+    } else {
+        let exception = Box::from_raw(exception as *mut Exception);
+        exception.cause
+    }
+}
+
+// Rust's exception class identifier, shared with the `gcc.rs` implementation.
+fn rust_exception_class() -> uw::_Unwind_Exception_Class {
+    // M O Z \0  R U S T -- vendor, language
+    0x4d4f5a_00_52555354
+}
+
+// This is required by the compiler to exist (e.g., it's a lang item), but
+// it's never actually called by the compiler because the wasm backend uses
+// `__gxx_wasm_personality_v0` only as a marker to select native wasm
+// exception handling. Hence this is just an aborting stub.
+#[lang = "eh_personality"]
+#[cfg(not(test))]
+fn rust_eh_personality() {
+    core::intrinsics::abort()
+}
@@ -8,12 +8,12 @@
 
 #![deny(unsafe_code)]
 
-use crate::{Delimiter, Level, LineColumn, Spacing};
+use crate::{Applicability, Delimiter, Level, LineColumn, Spacing};
 use std::fmt;
 use std::hash::Hash;
 use std::marker;
 use std::mem;
-use std::ops::Bound;
+use std::ops::{Bound, Range};
 use std::panic;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Once;
@@ -150,6 +150,13 @@ fn sub(
                     msg: &str,
                     span: $S::MultiSpan,
                 );
+                fn suggestion(
+                    $self: &mut $S::Diagnostic,
+                    msg: &str,
+                    span: $S::Span,
+                    replacement: &str,
+                    applicability: Applicability,
+                );
                 fn emit($self: $S::Diagnostic);
             },
             Span {
@@ -162,6 +169,7 @@ fn sub(
                 fn source($self: $S::Span) -> $S::Span;
                 fn start($self: $S::Span) -> LineColumn;
                 fn end($self: $S::Span) -> LineColumn;
+                fn byte_range($self: $S::Span) -> Range<usize>;
                 fn join($self: $S::Span, other: $S::Span) -> Option<$S::Span>;
                 fn resolved_at($self: $S::Span, at: $S::Span) -> $S::Span;
                 fn source_text($self: $S::Span) -> Option<String>;
@@ -357,11 +365,13 @@ fn unmark(self) -> Self::Unmarked {
     &'a str,
     String,
     usize,
+    Applicability,
     Delimiter,
     Level,
     LineColumn,
     Spacing,
     Bound<usize>,
+    Range<usize>,
 }
 
 rpc_encode_decode!(
@@ -380,6 +390,14 @@ enum Level {
         Help,
     }
 );
+rpc_encode_decode!(
+    enum Applicability {
+        MachineApplicable,
+        MaybeIncorrect,
+        HasPlaceholders,
+        Unspecified,
+    }
+);
 rpc_encode_decode!(struct LineColumn { line, column });
 rpc_encode_decode!(
     enum Spacing {
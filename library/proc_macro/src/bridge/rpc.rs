@@ -4,7 +4,7 @@
 use std::char;
 use std::io::Write;
 use std::num::NonZeroU32;
-use std::ops::Bound;
+use std::ops::{Bound, Range};
 use std::str;
 
 pub(super) type Writer = super::buffer::Buffer<u8>;
@@ -184,6 +184,19 @@ fn decode(r: &mut Reader<'a>, s: &mut S) -> Self {
     }
 }
 
+impl<S, T: Encode<S>> Encode<S> for Range<T> {
+    fn encode(self, w: &mut Writer, s: &mut S) {
+        self.start.encode(w, s);
+        self.end.encode(w, s);
+    }
+}
+
+impl<S, T: for<'s> DecodeMut<'a, 's, S>> DecodeMut<'a, '_, S> for Range<T> {
+    fn decode(r: &mut Reader<'a>, s: &mut S) -> Self {
+        DecodeMut::decode(r, s)..DecodeMut::decode(r, s)
+    }
+}
+
 rpc_encode_decode!(
     enum Bound<T> {
         Included(x),
@@ -15,6 +15,25 @@ pub enum Level {
     Help,
 }
 
+/// Indicates how confident the macro is that a suggested replacement is what
+/// the user intended, which tools such as `rustfix` use to decide whether to
+/// apply it automatically.
+#[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and should be
+    /// applied automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it is uncertain.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `(...)` or `{ /* fields */ }`
+    /// that the user has to fill in.
+    HasPlaceholders,
+    /// The applicability of the suggestion is unknown.
+    Unspecified,
+}
+
 /// Trait implemented by types that can be converted into a set of `Span`s.
 #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
 pub trait MultiSpan {
@@ -52,6 +71,44 @@ pub struct Diagnostic {
     message: String,
     spans: Vec<Span>,
     children: Vec<Diagnostic>,
+    suggestions: Vec<Suggestion>,
+}
+
+/// A replacement of the source code at a `Span`, suggested by a `Diagnostic`.
+#[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    span: Span,
+    message: String,
+    replacement: String,
+    applicability: Applicability,
+}
+
+#[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+impl Suggestion {
+    /// Returns the `Span` whose source code is to be replaced.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the message shown along with the suggestion.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the code that replaces the source code at `self.span()`.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Returns the `Applicability` of the suggestion.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn applicability(&self) -> Applicability {
+        self.applicability
+    }
 }
 
 macro_rules! diagnostic_child_methods {
@@ -97,7 +154,13 @@ impl Diagnostic {
     /// Creates a new diagnostic with the given `level` and `message`.
     #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
     pub fn new<T: Into<String>>(level: Level, message: T) -> Diagnostic {
-        Diagnostic { level, message: message.into(), spans: vec![], children: vec![] }
+        Diagnostic {
+            level,
+            message: message.into(),
+            spans: vec![],
+            children: vec![],
+            suggestions: vec![],
+        }
     }
 
     /// Creates a new diagnostic with the given `level` and `message` pointing to
@@ -108,7 +171,13 @@ pub fn spanned<S, T>(spans: S, level: Level, message: T) -> Diagnostic
         S: MultiSpan,
         T: Into<String>,
     {
-        Diagnostic { level, message: message.into(), spans: spans.into_spans(), children: vec![] }
+        Diagnostic {
+            level,
+            message: message.into(),
+            spans: spans.into_spans(),
+            children: vec![],
+            suggestions: vec![],
+        }
     }
 
     diagnostic_child_methods!(span_error, error, Level::Error);
@@ -116,6 +185,29 @@ pub fn spanned<S, T>(spans: S, level: Level, message: T) -> Diagnostic
     diagnostic_child_methods!(span_note, note, Level::Note);
     diagnostic_child_methods!(span_help, help, Level::Help);
 
+    /// Adds a suggestion to `self` to replace the source code at `span` with
+    /// `replacement`, shown with the given `message`.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn span_suggestion<T, U>(
+        mut self,
+        span: Span,
+        message: T,
+        replacement: U,
+        applicability: Applicability,
+    ) -> Diagnostic
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.suggestions.push(Suggestion {
+            span,
+            message: message.into(),
+            replacement: replacement.into(),
+            applicability,
+        });
+        self
+    }
+
     /// Returns the diagnostic `level` for `self`.
     #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
     pub fn level(&self) -> Level {
@@ -158,6 +250,12 @@ pub fn children(&self) -> Children<'_> {
         Children(self.children.iter())
     }
 
+    /// Returns the suggestions in `self`.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+
     /// Emit the diagnostic.
     #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
     pub fn emit(self) {
@@ -177,6 +275,9 @@ fn to_internal(spans: Vec<Span>) -> crate::bridge::client::MultiSpan {
         for c in self.children {
             diag.sub(c.level, &c.message[..], to_internal(c.spans));
         }
+        for s in self.suggestions {
+            diag.suggestion(&s.message[..], s.span.0, &s.replacement[..], s.applicability);
+        }
         diag.emit();
     }
 }
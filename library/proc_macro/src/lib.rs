@@ -40,10 +40,10 @@
 mod diagnostic;
 
 #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
-pub use diagnostic::{Diagnostic, Level, MultiSpan};
+pub use diagnostic::{Applicability, Diagnostic, Level, MultiSpan, Suggestion};
 
 use std::cmp::Ordering;
-use std::ops::RangeBounds;
+use std::ops::{Range, RangeBounds};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::{error, fmt, iter, mem};
@@ -357,6 +357,25 @@ pub fn end(&self) -> LineColumn {
         self.0.end().add_1_to_column()
     }
 
+    /// Gets the 1-indexed line in the source file on which this span starts.
+    #[unstable(feature = "proc_macro_span", issue = "54725")]
+    pub fn line(&self) -> usize {
+        self.start().line
+    }
+
+    /// Gets the 1-indexed column in the source file at which this span starts.
+    #[unstable(feature = "proc_macro_span", issue = "54725")]
+    pub fn column(&self) -> usize {
+        self.start().column
+    }
+
+    /// Returns the byte range of this span within its source file, i.e. the
+    /// byte offsets relative to the start of `self.source_file()`.
+    #[unstable(feature = "proc_macro_span", issue = "54725")]
+    pub fn byte_range(&self) -> Range<usize> {
+        self.0.byte_range()
+    }
+
     /// Creates a new span encompassing `self` and `other`.
     ///
     /// Returns `None` if `self` and `other` are from different files.
@@ -1230,6 +1249,8 @@ pub mod tracked_env {
     /// Retrieve an environment variable and add it to build dependency info.
     /// Build system executing the compiler will know that the variable was accessed during
     /// compilation, and will be able to rerun the build when the value of that variable changes.
+    /// The variable is recorded as an `# env-dep:` comment in the dep-info emitted by the
+    /// compiler, along with its value, or without one if it is unset.
     /// Besides the dependency tracking this function should be equivalent to `env::var` from the
     /// standard library, except that the argument must be UTF-8.
     #[unstable(feature = "proc_macro_tracked_env", issue = "74690")]
@@ -1247,6 +1268,10 @@ pub mod tracked_path {
 
     /// Track a file explicitly.
     ///
+    /// The file is added to the dep-info emitted by the compiler (`--emit=dep-info`), so a build
+    /// system executing the compiler will rerun the build, and the macro, when the file changes.
+    /// Relative paths are interpreted relative to the working directory of the compiler.
+    ///
     /// Commonly used for tracking asset preprocessing.
     #[unstable(feature = "track_path", issue = "73921")]
     pub fn path<P: AsRef<str>>(path: P) {
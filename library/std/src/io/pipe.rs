@@ -0,0 +1,254 @@
+use crate::fmt;
+use crate::io::{self, Initializer, IoSlice, IoSliceMut, Read, Write};
+use crate::sys::pipe::{self as imp, AnonPipe};
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+
+#[cfg(all(test, any(unix, windows), not(target_os = "emscripten")))]
+mod tests;
+
+/// Creates an anonymous pipe.
+///
+/// An anonymous pipe is a unidirectional data channel: bytes written to the
+/// [`PipeWriter`] can be read from the [`PipeReader`], in the order in which
+/// they were written.
+///
+/// # Behavior
+///
+/// Reading from the [`PipeReader`] blocks until data is available or all
+/// [`PipeWriter`]s (including clones made with [`PipeWriter::try_clone`])
+/// have been dropped, at which point reads return `Ok(0)`. Writing to a pipe
+/// whose [`PipeReader`]s have all been dropped returns an error of kind
+/// [`io::ErrorKind::BrokenPipe`].
+///
+/// Both halves are created without being inheritable by child processes;
+/// they can be passed to a child process explicitly by converting them into
+/// a [`Stdio`](crate::process::Stdio).
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to the `pipe2` function (or `pipe`
+/// followed by setting `FD_CLOEXEC`) on Unix and the `CreatePipe` function on
+/// Windows. Other platforms return an error of kind
+/// [`io::ErrorKind::Unsupported`]. Note that this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+///
+/// # Examples
+///
+/// Feeding the output of one process into another one and collecting the
+/// combined output of both:
+///
+/// ```no_run
+/// #![feature(anonymous_pipe)]
+/// # fn main() -> std::io::Result<()> {
+/// use std::io::{self, Read};
+/// use std::process::Command;
+///
+/// let (mut reader, writer) = io::pipe()?;
+///
+/// let mut echo = Command::new("echo").arg("hello").stdout(writer.try_clone()?).spawn()?;
+/// let mut ls = Command::new("ls").stdout(writer).spawn()?;
+///
+/// // Both `Command`s are temporaries, so the writers they own are closed right
+/// // after spawning. The read below therefore returns once both children have
+/// // closed their stdout.
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output)?;
+///
+/// echo.wait()?;
+/// ls.wait()?;
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+#[inline]
+pub fn pipe() -> io::Result<(PipeReader, PipeWriter)> {
+    imp::anon_pipe_pair().map(|(reader, writer)| (PipeReader(reader), PipeWriter(writer)))
+}
+
+/// The read end of an anonymous pipe, created by [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeReader(AnonPipe);
+
+/// The write end of an anonymous pipe, created by [`pipe`].
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+pub struct PipeWriter(AnonPipe);
+
+impl PipeReader {
+    /// Creates a new `PipeReader` instance that shares the same underlying
+    /// pipe as the existing one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// # fn main() -> std::io::Result<()> {
+    /// let (reader, _writer) = std::io::pipe()?;
+    /// let reader_clone = reader.try_clone()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeReader> {
+        self.0.try_clone().map(PipeReader)
+    }
+}
+
+impl PipeWriter {
+    /// Creates a new `PipeWriter` instance that shares the same underlying
+    /// pipe as the existing one.
+    ///
+    /// Reads from the corresponding [`PipeReader`] only return `Ok(0)` once
+    /// all clones of the writer have been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(anonymous_pipe)]
+    /// # fn main() -> std::io::Result<()> {
+    /// let (_reader, writer) = std::io::pipe()?;
+    /// let writer_clone = writer.try_clone()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "anonymous_pipe", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<PipeWriter> {
+        self.0.try_clone().map(PipeWriter)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Read for &PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_read_vectored(&self) -> bool {
+        self.0.is_read_vectored()
+    }
+
+    #[inline]
+    unsafe fn initializer(&self) -> Initializer {
+        // SAFETY: Read is guaranteed to work on uninitialized memory
+        unsafe { Initializer::nop() }
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl Write for &PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl AsInner<AnonPipe> for PipeReader {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeReader {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeReader {
+    fn from_inner(pipe: AnonPipe) -> PipeReader {
+        PipeReader(pipe)
+    }
+}
+
+impl AsInner<AnonPipe> for PipeWriter {
+    fn as_inner(&self) -> &AnonPipe {
+        &self.0
+    }
+}
+
+impl IntoInner<AnonPipe> for PipeWriter {
+    fn into_inner(self) -> AnonPipe {
+        self.0
+    }
+}
+
+impl FromInner<AnonPipe> for PipeWriter {
+    fn from_inner(pipe: AnonPipe) -> PipeWriter {
+        PipeWriter(pipe)
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("PipeReader { .. }")
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl fmt::Debug for PipeWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("PipeWriter { .. }")
+    }
+}
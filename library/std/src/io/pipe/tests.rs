@@ -0,0 +1,44 @@
+use crate::io::{self, Read, Write};
+use crate::thread;
+
+#[test]
+fn pipe_creation_clone_and_rw() {
+    let (rx, tx) = io::pipe().unwrap();
+
+    tx.try_clone().unwrap().write_all(b"12345").unwrap();
+    drop(tx);
+
+    let mut rx2 = rx.try_clone().unwrap();
+    drop(rx);
+
+    let mut s = String::new();
+    rx2.read_to_string(&mut s).unwrap();
+    drop(rx2);
+    assert_eq!(s, "12345");
+}
+
+#[test]
+fn pipe_read_returns_eof_once_all_writers_are_dropped() {
+    let (mut rx, tx) = io::pipe().unwrap();
+    let tx2 = tx.try_clone().unwrap();
+
+    let writer = thread::spawn(move || {
+        (&tx).write_all(b"hello ").unwrap();
+        (&tx2).write_all(b"world").unwrap();
+    });
+
+    let mut s = String::new();
+    rx.read_to_string(&mut s).unwrap();
+    writer.join().unwrap();
+    assert_eq!(s, "hello world");
+}
+
+#[test]
+fn pipe_write_fails_once_reader_is_dropped() {
+    let (rx, mut tx) = io::pipe().unwrap();
+    drop(rx);
+
+    // This relies on the runtime ignoring SIGPIPE on unix.
+    let err = tx.write_all(b"hello").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
@@ -146,6 +146,50 @@ fn into_raw_fd(self) -> RawFd {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeReader {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().fd().raw()
+    }
+}
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawFd for io::PipeReader {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> io::PipeReader {
+        io::PipeReader::from_inner(sys::pipe::AnonPipe::from_inner(fd))
+    }
+}
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeReader {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_fd().into_raw()
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl AsRawFd for io::PipeWriter {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_inner().fd().raw()
+    }
+}
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl FromRawFd for io::PipeWriter {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> io::PipeWriter {
+        io::PipeWriter::from_inner(sys::pipe::AnonPipe::from_inner(fd))
+    }
+}
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl IntoRawFd for io::PipeWriter {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.into_inner().into_fd().into_raw()
+    }
+}
+
 #[stable(feature = "asraw_stdio", since = "1.21.0")]
 impl AsRawFd for io::Stdin {
     #[inline]
@@ -1382,6 +1382,66 @@ fn from(file: fs::File) -> Stdio {
     }
 }
 
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeReader> for Stdio {
+    /// Converts the read end of an anonymous pipe into a `Stdio`, typically
+    /// used as the standard input of a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Write};
+    /// use std::process::Command;
+    ///
+    /// let (reader, mut writer) = io::pipe().unwrap();
+    ///
+    /// let mut rev = Command::new("rev")
+    ///     .stdin(reader)  // Implicit PipeReader conversion into a Stdio
+    ///     .spawn()
+    ///     .expect("failed to spawn rev");
+    ///
+    /// writer.write_all(b"Hello, world!").unwrap();
+    /// // Close the write end so that `rev` sees the end of its input.
+    /// drop(writer);
+    /// rev.wait().unwrap();
+    /// ```
+    fn from(pipe: io::PipeReader) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
+#[unstable(feature = "anonymous_pipe", issue = "none")]
+impl From<io::PipeWriter> for Stdio {
+    /// Converts the write end of an anonymous pipe into a `Stdio`, typically
+    /// used as the standard output or standard error of a child process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// #![feature(anonymous_pipe)]
+    /// use std::io::{self, Read};
+    /// use std::process::Command;
+    ///
+    /// let (mut reader, writer) = io::pipe().unwrap();
+    ///
+    /// let mut echo = Command::new("echo")
+    ///     .arg("Hello, world!")
+    ///     .stdout(writer)  // Implicit PipeWriter conversion into a Stdio
+    ///     .spawn()
+    ///     .expect("failed to spawn echo");
+    ///
+    /// let mut output = String::new();
+    /// reader.read_to_string(&mut output).unwrap();
+    /// echo.wait().unwrap();
+    ///
+    /// assert_eq!(output, "Hello, world!\n");
+    /// ```
+    fn from(pipe: io::PipeWriter) -> Stdio {
+        Stdio::from_inner(pipe.into_inner().into())
+    }
+}
+
 /// Describes the result of a process after it has terminated.
 ///
 /// This `struct` is used to represent the exit status or other termination of a child process.
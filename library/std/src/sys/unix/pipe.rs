@@ -2,6 +2,9 @@
 use crate::mem;
 use crate::sys::fd::FileDesc;
 use crate::sys::{cvt, cvt_r};
+use crate::sys_common::FromInner;
+
+use libc::c_int;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Anonymous pipes
@@ -38,7 +41,18 @@ pub fn anon_pipe() -> io::Result<(AnonPipe, AnonPipe)> {
     }
 }
 
+/// Creates the pipe behind `io::pipe`. Unlike on Windows, the pipes used for
+/// `Command`'s stdio are already suitable for this, so this is the same as
+/// `anon_pipe`.
+pub fn anon_pipe_pair() -> io::Result<(AnonPipe, AnonPipe)> {
+    anon_pipe()
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        self.0.duplicate().map(AnonPipe)
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
@@ -73,6 +87,12 @@ pub fn into_fd(self) -> FileDesc {
     }
 }
 
+impl FromInner<c_int> for AnonPipe {
+    fn from_inner(fd: c_int) -> AnonPipe {
+        AnonPipe(FileDesc::new(fd))
+    }
+}
+
 pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) -> io::Result<()> {
     // Set both pipes into nonblocking mode as we're gonna be reading from both
     // in the `select` loop below, and we wouldn't want one to block the other!
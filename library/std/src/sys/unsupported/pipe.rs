@@ -1,8 +1,17 @@
 use crate::io::{self, IoSlice, IoSliceMut};
+use crate::sys::unsupported;
 
 pub struct AnonPipe(!);
 
+pub fn anon_pipe_pair() -> io::Result<(AnonPipe, AnonPipe)> {
+    unsupported()
+}
+
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        self.0
+    }
+
     pub fn read(&self, _buf: &mut [u8]) -> io::Result<usize> {
         self.0
     }
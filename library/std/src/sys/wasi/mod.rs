@@ -22,14 +22,10 @@
 pub mod args;
 #[path = "../unix/cmath.rs"]
 pub mod cmath;
-#[path = "../unsupported/condvar.rs"]
-pub mod condvar;
 pub mod env;
 pub mod fd;
 pub mod fs;
 pub mod io;
-#[path = "../unsupported/mutex.rs"]
-pub mod mutex;
 pub mod net;
 pub mod os;
 #[path = "../unix/os_str.rs"]
@@ -40,8 +36,6 @@
 pub mod pipe;
 #[path = "../unsupported/process.rs"]
 pub mod process;
-#[path = "../unsupported/rwlock.rs"]
-pub mod rwlock;
 pub mod stdio;
 pub mod thread;
 #[path = "../unsupported/thread_local_dtor.rs"]
@@ -50,6 +44,26 @@
 pub mod thread_local_key;
 pub mod time;
 
+cfg_if::cfg_if! {
+    if #[cfg(target_feature = "atomics")] {
+        #[path = "../wasm/atomics/condvar.rs"]
+        pub mod condvar;
+        #[path = "../wasm/atomics/mutex.rs"]
+        pub mod mutex;
+        #[path = "../wasm/atomics/rwlock.rs"]
+        pub mod rwlock;
+        #[path = "../wasm/atomics/futex.rs"]
+        pub mod futex;
+    } else {
+        #[path = "../unsupported/condvar.rs"]
+        pub mod condvar;
+        #[path = "../unsupported/mutex.rs"]
+        pub mod mutex;
+        #[path = "../unsupported/rwlock.rs"]
+        pub mod rwlock;
+    }
+}
+
 #[path = "../unsupported/common.rs"]
 #[deny(unsafe_op_in_unsafe_fn)]
 #[allow(unused)]
@@ -7,14 +7,104 @@
 use crate::sys::unsupported;
 use crate::time::Duration;
 
-pub struct Thread(!);
+cfg_if::cfg_if! {
+    if #[cfg(target_feature = "atomics")] {
+        use crate::cmp;
+        use crate::ptr;
+
+        // `wasi-libc` implements pthreads on top of the `thread-spawn` import
+        // from the wasi-threads proposal, but the `libc` crate doesn't expose
+        // these bindings for wasi yet, so declare the few we need here.
+        mod libc {
+            pub use libc::*;
+
+            // https://github.com/WebAssembly/wasi-libc/blob/main/libc-top-half/musl/include/alltypes.h.in
+            #[repr(C)]
+            pub struct pthread_attr_t {
+                __u: [c_uint; 9],
+            }
+
+            #[allow(non_camel_case_types)]
+            pub type pthread_t = *mut c_void;
+
+            extern "C" {
+                pub fn pthread_create(
+                    native: *mut pthread_t,
+                    attr: *const pthread_attr_t,
+                    f: extern "C" fn(*mut c_void) -> *mut c_void,
+                    value: *mut c_void,
+                ) -> c_int;
+                pub fn pthread_join(native: pthread_t, value: *mut *mut c_void) -> c_int;
+                pub fn pthread_attr_init(attrp: *mut pthread_attr_t) -> c_int;
+                pub fn pthread_attr_setstacksize(
+                    attr: *mut pthread_attr_t,
+                    stack_size: size_t,
+                ) -> c_int;
+                pub fn pthread_attr_destroy(attr: *mut pthread_attr_t) -> c_int;
+                pub fn pthread_detach(thread: pthread_t) -> c_int;
+            }
+        }
+
+        pub struct Thread {
+            id: libc::pthread_t,
+        }
+
+        impl Drop for Thread {
+            fn drop(&mut self) {
+                let ret = unsafe { libc::pthread_detach(self.id) };
+                debug_assert_eq!(ret, 0);
+            }
+        }
+    } else {
+        pub struct Thread(!);
+    }
+}
 
 pub const DEFAULT_MIN_STACK_SIZE: usize = 4096;
 
 impl Thread {
-    // unsafe: see thread::Builder::spawn_unchecked for safety requirements
-    pub unsafe fn new(_stack: usize, _p: Box<dyn FnOnce()>) -> io::Result<Thread> {
-        unsupported()
+    cfg_if::cfg_if! {
+        if #[cfg(target_feature = "atomics")] {
+            // unsafe: see thread::Builder::spawn_unchecked for safety requirements
+            pub unsafe fn new(stack: usize, p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+                let p = Box::into_raw(box p);
+                let mut native: libc::pthread_t = ptr::null_mut();
+                let mut attr: libc::pthread_attr_t = unsafe { mem::zeroed() };
+                assert_eq!(unsafe { libc::pthread_attr_init(&mut attr) }, 0);
+
+                let stack_size = cmp::max(stack, DEFAULT_MIN_STACK_SIZE);
+                assert_eq!(unsafe { libc::pthread_attr_setstacksize(&mut attr, stack_size) }, 0);
+
+                let ret =
+                    unsafe { libc::pthread_create(&mut native, &attr, thread_start, p as *mut _) };
+                // Note: if the thread creation fails and this assert fails, then p will
+                // be leaked. However, an alternative design could cause double-free
+                // which is clearly worse.
+                assert_eq!(unsafe { libc::pthread_attr_destroy(&mut attr) }, 0);
+
+                return if ret != 0 {
+                    // The thread failed to start and as a result p was not consumed. Therefore, it is
+                    // safe to reconstruct the box so that it gets deallocated.
+                    drop(unsafe { Box::from_raw(p) });
+                    Err(io::Error::from_raw_os_error(ret))
+                } else {
+                    Ok(Thread { id: native })
+                };
+
+                extern "C" fn thread_start(main: *mut libc::c_void) -> *mut libc::c_void {
+                    unsafe {
+                        // Finally, let's run some code.
+                        Box::from_raw(main as *mut Box<dyn FnOnce()>)();
+                    }
+                    ptr::null_mut()
+                }
+            }
+        } else {
+            // unsafe: see thread::Builder::spawn_unchecked for safety requirements
+            pub unsafe fn new(_stack: usize, _p: Box<dyn FnOnce()>) -> io::Result<Thread> {
+                unsupported()
+            }
+        }
     }
 
     pub fn yield_now() {
@@ -60,7 +150,15 @@ pub fn sleep(dur: Duration) {
     }
 
     pub fn join(self) {
-        self.0
+        cfg_if::cfg_if! {
+            if #[cfg(target_feature = "atomics")] {
+                let ret = unsafe { libc::pthread_join(self.id, ptr::null_mut()) };
+                mem::forget(self);
+                assert!(ret == 0, "failed to join thread: {}", io::Error::from_raw_os_error(ret));
+            } else {
+                self.0
+            }
+        }
     }
 }
 
@@ -77,3 +175,38 @@ pub unsafe fn init() -> Option<Guard> {
         None
     }
 }
+
+// We currently just use our own thread-local to store our
+// current thread's ID, and then we lazily initialize it to something allocated
+// from a global counter.
+#[cfg(target_feature = "atomics")]
+pub fn my_id() -> u32 {
+    use crate::sync::atomic::{AtomicU32, Ordering::SeqCst};
+
+    static NEXT_ID: AtomicU32 = AtomicU32::new(0);
+
+    #[thread_local]
+    static mut MY_ID: u32 = 0;
+
+    unsafe {
+        // If our thread ID isn't set yet then we need to allocate one. Do so
+        // with with a simple "atomically add to a global counter" strategy.
+        // This strategy doesn't handled what happens when the counter
+        // overflows, however, so just abort everything once the counter
+        // overflows and eventually we could have some sort of recycling scheme
+        // (or maybe this is all totally irrelevant by that point!). In any case
+        // though we're using a CAS loop instead of a `fetch_add` to ensure that
+        // the global counter never overflows.
+        if MY_ID == 0 {
+            let mut cur = NEXT_ID.load(SeqCst);
+            MY_ID = loop {
+                let next = cur.checked_add(1).unwrap_or_else(|| crate::process::abort());
+                match NEXT_ID.compare_exchange(cur, next, SeqCst, SeqCst) {
+                    Ok(_) => break next,
+                    Err(i) => cur = i,
+                }
+            };
+        }
+        MY_ID
+    }
+}
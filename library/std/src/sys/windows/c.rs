@@ -924,6 +924,12 @@ pub fn WaitForMultipleObjects(
         bWaitAll: BOOL,
         dwMilliseconds: DWORD,
     ) -> DWORD;
+    pub fn CreatePipe(
+        hReadPipe: LPHANDLE,
+        hWritePipe: LPHANDLE,
+        lpPipeAttributes: LPSECURITY_ATTRIBUTES,
+        nSize: DWORD,
+    ) -> BOOL;
     pub fn CreateNamedPipeW(
         lpName: LPCWSTR,
         dwOpenMode: DWORD,
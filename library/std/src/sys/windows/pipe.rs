@@ -9,9 +9,11 @@
 use crate::sync::atomic::AtomicUsize;
 use crate::sync::atomic::Ordering::SeqCst;
 use crate::sys::c;
+use crate::sys::cvt;
 use crate::sys::fs::{File, OpenOptions};
 use crate::sys::handle::Handle;
 use crate::sys::hashmap_random_keys;
+use crate::sys_common::FromInner;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Anonymous pipes
@@ -155,6 +157,19 @@ pub fn anon_pipe(ours_readable: bool, their_handle_inheritable: bool) -> io::Res
     }
 }
 
+/// Creates the pipe behind `io::pipe`, returning its read and write ends.
+///
+/// Unlike the pipes created by `anon_pipe`, neither end is opened in overlapped
+/// mode, so both can be used with plain blocking reads and writes, and neither
+/// is inheritable. They can still be handed to a child process as stdio since
+/// `Command` duplicates stdio handles as inheritable when spawning.
+pub fn anon_pipe_pair() -> io::Result<(AnonPipe, AnonPipe)> {
+    let mut read = c::INVALID_HANDLE_VALUE;
+    let mut write = c::INVALID_HANDLE_VALUE;
+    cvt(unsafe { c::CreatePipe(&mut read, &mut write, ptr::null_mut(), 0) })?;
+    Ok((AnonPipe { inner: Handle::new(read) }, AnonPipe { inner: Handle::new(write) }))
+}
+
 fn random_number() -> usize {
     static N: AtomicUsize = AtomicUsize::new(0);
     loop {
@@ -167,6 +182,11 @@ fn random_number() -> usize {
 }
 
 impl AnonPipe {
+    pub fn try_clone(&self) -> io::Result<AnonPipe> {
+        let inner = self.inner.duplicate(0, false, c::DUPLICATE_SAME_ACCESS)?;
+        Ok(AnonPipe { inner })
+    }
+
     pub fn handle(&self) -> &Handle {
         &self.inner
     }
@@ -201,6 +221,12 @@ pub fn is_write_vectored(&self) -> bool {
     }
 }
 
+impl FromInner<c::HANDLE> for AnonPipe {
+    fn from_inner(handle: c::HANDLE) -> AnonPipe {
+        AnonPipe { inner: Handle::new(handle) }
+    }
+}
+
 pub fn read2(p1: AnonPipe, v1: &mut Vec<u8>, p2: AnonPipe, v2: &mut Vec<u8>) -> io::Result<()> {
     let p1 = p1.into_handle();
     let p2 = p2.into_handle();
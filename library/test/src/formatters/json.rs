@@ -8,6 +8,12 @@
     types::TestDesc,
 };
 
+/// The version of the JSON output format, reported by the `suite` `started` event.
+///
+/// It is bumped whenever an event or a field is removed, or changes its meaning. New events
+/// and fields may be added without bumping it, so consumers should ignore what they don't know.
+const FORMAT_VERSION: u32 = 1;
+
 pub(crate) struct JsonFormatter<T> {
     out: OutputLocation<T>,
 }
@@ -60,10 +66,18 @@ fn write_event(
 }
 
 impl<T: Write> OutputFormatter for JsonFormatter<T> {
-    fn write_run_start(&mut self, test_count: usize) -> io::Result<()> {
+    fn write_run_start(&mut self, test_count: usize, shuffle_seed: Option<u64>) -> io::Result<()> {
+        let shuffle_seed_json = if let Some(shuffle_seed) = shuffle_seed {
+            format!(r#", "shuffle_seed": {}"#, shuffle_seed)
+        } else {
+            String::new()
+        };
         self.writeln_message(&*format!(
-            r#"{{ "type": "suite", "event": "started", "test_count": {} }}"#,
-            test_count
+            "{{ \"type\": \"suite\", \
+             \"event\": \"started\", \
+             \"format_version\": {}, \
+             \"test_count\": {}{} }}",
+            FORMAT_VERSION, test_count, shuffle_seed_json
         ))
     }
 
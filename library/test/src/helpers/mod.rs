@@ -5,3 +5,4 @@
 pub mod exit_code;
 pub mod isatty;
 pub mod metrics;
+pub mod shuffle;
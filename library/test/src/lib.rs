@@ -91,6 +91,7 @@ pub mod test {
 use event::{CompletedTest, TestEvent};
 use helpers::concurrency::get_concurrency;
 use helpers::exit_code::get_exit_code;
+use helpers::shuffle::{get_shuffle_seed, shuffle_tests};
 use options::{Concurrent, RunStrategy};
 use test_result::*;
 use time::TestExecTime;
@@ -247,7 +248,9 @@ struct TimeoutEntry {
 
     let filtered_descs = filtered_tests.iter().map(|t| t.desc.clone()).collect();
 
-    let event = TestEvent::TeFiltered(filtered_descs);
+    let shuffle_seed = get_shuffle_seed(opts);
+
+    let event = TestEvent::TeFiltered(filtered_descs, shuffle_seed);
     notify_about_test_event(event)?;
 
     let (filtered_tests, filtered_benchs): (Vec<_>, _) = filtered_tests
@@ -259,7 +262,11 @@ struct TimeoutEntry {
     let concurrency = opts.test_threads.unwrap_or_else(get_concurrency);
 
     let mut remaining = filtered_tests;
-    remaining.reverse();
+    if let Some(shuffle_seed) = shuffle_seed {
+        shuffle_tests(shuffle_seed, &mut remaining);
+    } else {
+        remaining.reverse();
+    }
     let mut pending = 0;
 
     let (tx, rx) = channel::<CompletedTest>();
@@ -476,6 +483,7 @@ struct TestRunOpts {
         pub strategy: RunStrategy,
         pub nocapture: bool,
         pub concurrency: Concurrent,
+        pub report_time: bool,
         pub time: Option<time::TestTimeOptions>,
     }
 
@@ -494,7 +502,7 @@ fn run_test_inner(
                 id,
                 desc,
                 opts.nocapture,
-                opts.time.is_some(),
+                opts.report_time,
                 testfn,
                 monitor_ch,
                 opts.time,
@@ -503,7 +511,7 @@ fn run_test_inner(
                 id,
                 desc,
                 opts.nocapture,
-                opts.time.is_some(),
+                opts.report_time,
                 monitor_ch,
                 opts.time,
             ),
@@ -533,8 +541,17 @@ fn run_test_inner(
         }
     }
 
-    let test_run_opts =
-        TestRunOpts { strategy, nocapture: opts.nocapture, concurrency, time: opts.time_options };
+    // The JSON format always reports how long each test took, so measure it even without
+    // `--report-time`. `Instant` isn't available on wasm targets or under miri isolation.
+    let report_time = opts.time_options.is_some()
+        || (opts.format == OutputFormat::Json && !cfg!(target_arch = "wasm32") && !cfg!(miri));
+    let test_run_opts = TestRunOpts {
+        strategy,
+        nocapture: opts.nocapture,
+        concurrency,
+        report_time,
+        time: opts.time_options,
+    };
 
     match testfn {
         DynBenchFn(bencher) => {
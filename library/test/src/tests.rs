@@ -4,6 +4,7 @@
     bench::Bencher,
     console::OutputLocation,
     formatters::PrettyFormatter,
+    helpers::shuffle::shuffle_tests,
     options::OutputFormat,
     test::{
         filter_tests,
@@ -48,6 +49,8 @@ fn new() -> TestOpts {
             test_threads: None,
             skip: vec![],
             time_options: None,
+            shuffle: false,
+            shuffle_seed: None,
             options: Options::new(),
         }
     }
@@ -428,6 +431,36 @@ fn parse_include_ignored_flag() {
     assert_eq!(opts.run_ignored, RunIgnored::Yes);
 }
 
+#[test]
+fn parse_shuffle_seed_option() {
+    let args = vec![
+        "progname".to_string(),
+        "-Zunstable-options".to_string(),
+        "--shuffle-seed".to_string(),
+        "42".to_string(),
+    ];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.shuffle_seed, Some(42));
+}
+
+#[test]
+fn parse_shuffle_options_require_unstable_options() {
+    let args = vec!["progname".to_string(), "--shuffle".to_string()];
+    let err = parse_opts(&args).unwrap().unwrap_err();
+    assert!(err.contains("-Z unstable-options"), "unexpected error: {}", err);
+
+    let args = vec!["progname".to_string(), "--shuffle-seed".to_string(), "42".to_string()];
+    let err = parse_opts(&args).unwrap().unwrap_err();
+    assert!(err.contains("-Z unstable-options"), "unexpected error: {}", err);
+}
+
+#[test]
+fn parse_json_format() {
+    let args = vec!["progname".to_string(), "--format".to_string(), "json".to_string()];
+    let opts = parse_opts(&args).unwrap().unwrap();
+    assert_eq!(opts.format, OutputFormat::Json);
+}
+
 #[test]
 pub fn filter_for_ignored_option() {
     // When we run ignored tests the test filter should filter out all the
@@ -624,6 +657,43 @@ fn testfn() {}
     }
 }
 
+fn shuffled_test_names(shuffle_seed: u64) -> Vec<String> {
+    fn testfn() {}
+    let mut tests: Vec<_> = (0..100)
+        .map(|i| {
+            let test = TestDescAndFn {
+                desc: TestDesc {
+                    name: DynTestName(format!("test_{}", i)),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                    allow_fail: false,
+                    compile_fail: false,
+                    no_run: false,
+                    test_type: TestType::Unknown,
+                },
+                testfn: DynTestFn(Box::new(testfn)),
+            };
+            (TestId(i), test)
+        })
+        .collect();
+    shuffle_tests(shuffle_seed, &mut tests);
+    tests.into_iter().map(|(_, test)| test.desc.name.to_string()).collect()
+}
+
+#[test]
+pub fn shuffle_tests_is_reproducible() {
+    let shuffled = shuffled_test_names(42);
+    assert_eq!(shuffled, shuffled_test_names(42));
+    assert_ne!(shuffled, shuffled_test_names(43));
+
+    let mut sorted = shuffled.clone();
+    sorted.sort();
+    let mut expected: Vec<_> = (0..100).map(|i| format!("test_{}", i)).collect();
+    expected.sort();
+    assert_eq!(sorted, expected);
+    assert_ne!(shuffled, (0..100).map(|i| format!("test_{}", i)).collect::<Vec<_>>());
+}
+
 #[test]
 pub fn test_metricmap_compare() {
     let mut m1 = MetricMap::new();
@@ -5,6 +5,9 @@
 #![feature(staged_api)]
 #![feature(static_nobundle)]
 #![feature(c_unwind)]
+#![feature(cfg_panic)]
+#![feature(core_intrinsics)]
+#![feature(link_llvm_intrinsics)]
 #![cfg_attr(not(target_env = "msvc"), feature(libc))]
 
 cfg_if::cfg_if! {
@@ -25,9 +28,13 @@
     ))] {
         mod libunwind;
         pub use libunwind::*;
+    } else if #[cfg(target_family = "wasm")] {
+        // wasm (not emscripten, which is "unix" family) unwinds with the
+        // native exception-handling instructions.
+        mod wasm;
+        pub use wasm::*;
     } else {
         // no unwinder on the system!
-        // - wasm32 (not emscripten, which is "unix" family)
         // - os=none ("bare metal" targets)
         // - os=hermit
         // - os=uefi
@@ -128,6 +128,12 @@ fn run(self, builder: &Builder<'_>) {
     }
 }
 
+/// Returns the directory inside a `wasi-root` sysroot holding the libraries for
+/// `target`; the threaded target uses a separately compiled `wasi-libc`.
+fn wasi_libdir(target: TargetSelection) -> &'static str {
+    if target.ends_with("-threads") { "lib/wasm32-wasi-threads" } else { "lib/wasm32-wasi" }
+}
+
 fn copy_and_stamp(
     builder: &Builder<'_>,
     libdir: &Path,
@@ -208,13 +214,13 @@ fn copy_self_contained_objects(
             builder.copy(&src, &target);
             target_deps.push((target, DependencyType::TargetSelfContained));
         }
-    } else if target.ends_with("-wasi") {
+    } else if target.contains("-wasi") {
         let srcdir = builder
             .wasi_root(target)
             .unwrap_or_else(|| {
                 panic!("Target {:?} does not have a \"wasi-root\" key", target.triple)
             })
-            .join("lib/wasm32-wasi");
+            .join(wasi_libdir(target));
         for &obj in &["crt1-command.o", "crt1-reactor.o"] {
             copy_and_stamp(
                 builder,
@@ -301,9 +307,9 @@ pub fn std_cargo(builder: &Builder<'_>, target: TargetSelection, stage: u32, car
             }
         }
 
-        if target.ends_with("-wasi") {
+        if target.contains("-wasi") {
             if let Some(p) = builder.wasi_root(target) {
-                let root = format!("native={}/lib/wasm32-wasi", p.to_str().unwrap());
+                let root = format!("native={}/{}", p.to_str().unwrap(), wasi_libdir(target));
                 cargo.rustflag("-L").rustflag(&root);
             }
         }
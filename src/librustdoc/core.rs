@@ -256,6 +256,7 @@ impl<'tcx> DocContext<'tcx> {
     interface::Config {
         opts: sessopts,
         crate_cfg: interface::parse_cfgspecs(cfgs),
+        crate_check_cfg: Default::default(),
         input,
         input_path: cpath,
         output_file: None,
@@ -90,6 +90,7 @@
     let config = interface::Config {
         opts: sessopts,
         crate_cfg: interface::parse_cfgspecs(cfgs),
+        crate_check_cfg: Default::default(),
         input,
         input_path: None,
         output_file: None,
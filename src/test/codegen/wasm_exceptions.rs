@@ -0,0 +1,65 @@
+// only-wasm32-bare
+// compile-flags: -C panic=unwind
+// min-llvm-version: 13.0
+
+#![crate_type = "lib"]
+#![feature(core_intrinsics)]
+#![feature(c_unwind)]
+
+extern "C-unwind" {
+    fn may_panic();
+}
+
+extern "C" {
+    fn log_number(number: usize);
+}
+
+struct LogOnDrop;
+
+impl Drop for LogOnDrop {
+    fn drop(&mut self) {
+        unsafe {
+            log_number(0);
+        }
+    }
+}
+
+// CHECK-LABEL: @test_cleanup() {{.*}} personality {{.*}} @__gxx_wasm_personality_v0
+#[no_mangle]
+pub fn test_cleanup() {
+    let _log_on_drop = LogOnDrop;
+    unsafe {
+        may_panic();
+    }
+
+    // CHECK-NOT: call
+    // CHECK: invoke void @may_panic()
+    // CHECK: %cleanuppad = cleanuppad within none []
+}
+
+// CHECK-LABEL: @test_rtry
+#[no_mangle]
+pub fn test_rtry() {
+    unsafe {
+        core::intrinsics::r#try(
+            |_| {
+                may_panic();
+            },
+            core::ptr::null_mut(),
+            |data, exception| {
+                log_number(data as usize);
+                log_number(exception as usize);
+            },
+        );
+    }
+
+    // CHECK-NOT: call
+    // CHECK: invoke void @may_panic()
+    // CHECK: {{.*}} = catchswitch within none [label {{.*}}] unwind to caller
+    // CHECK: {{.*}} = catchpad within {{.*}} [i8* null]
+    // CHECK: {{.*}} = tail call i8* @llvm.wasm.get.exception(token {{.*}})
+    // CHECK: {{.*}} = tail call i32 @llvm.wasm.get.ehselector(token {{.*}})
+    // CHECK: call void @log_number
+    // CHECK: call void @log_number
+    // CHECK: catchret
+}
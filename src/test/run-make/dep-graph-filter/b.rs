@@ -0,0 +1,11 @@
+pub fn unchanged() -> u32 {
+    1
+}
+
+pub fn changed() -> u64 {
+    3
+}
+
+pub fn added() -> u32 {
+    4
+}
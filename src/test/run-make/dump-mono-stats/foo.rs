@@ -0,0 +1,8 @@
+pub fn bar() {}
+
+pub fn generic<T>(_: T) {}
+
+pub fn instantiate_generic() {
+    generic(0u32);
+    generic("str");
+}
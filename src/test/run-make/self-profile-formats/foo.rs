@@ -0,0 +1,7 @@
+pub fn foo() -> u32 {
+    bar() + bar()
+}
+
+fn bar() -> u32 {
+    1
+}
@@ -0,0 +1,16 @@
+#!/usr/bin/env python
+
+import sys
+import json
+
+# Every line must be a JSON object with the given fields, and the named entry must be present
+fields = sys.argv[1].split(',')
+name_field, expected = sys.argv[2].split('=')
+
+found = False
+for line in sys.stdin:
+    entry = json.loads(line)
+    assert sorted(entry.keys()) == sorted(fields), entry
+    if entry[name_field] == expected:
+        found = True
+assert found, "no entry with {} `{}`".format(name_field, expected)
@@ -0,0 +1,29 @@
+// run-pass
+
+#![feature(cfg_version)]
+
+#[cfg(version("1.0"))]
+fn old() -> bool {
+    true
+}
+
+#[cfg(not(version("1.0")))]
+fn old() -> bool {
+    false
+}
+
+#[cfg(version("999.0.0"))]
+fn future() -> bool {
+    true
+}
+
+#[cfg(not(version("999.0.0")))]
+fn future() -> bool {
+    false
+}
+
+fn main() {
+    assert!(old());
+    assert!(!future());
+    assert!(cfg!(all(version("1.45"), not(version("999.0")))));
+}
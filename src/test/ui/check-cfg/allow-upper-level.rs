@@ -0,0 +1,23 @@
+// This test checks that `#[allow(unexpected_cfgs)]` on an enclosing item applies to the
+// `#[cfg]` attributes and `cfg!` invocations inside of it
+//
+// check-pass
+// compile-flags: --check-cfg=cfg() -Z unstable-options
+
+#[allow(unexpected_cfgs)]
+mod aa {
+    #[cfg(FALSE)]
+    fn bar() {}
+}
+
+#[allow(unexpected_cfgs)]
+fn foo() {
+    #[cfg(FALSE)]
+    let _x = 0;
+
+    if cfg!(FALSE) {}
+}
+
+fn main() {
+    foo();
+}
@@ -0,0 +1,3 @@
+// compile-flags: --check-cfg=cfg(foo,values(bar)) -Z unstable-options
+// error-pattern: invalid `--check-cfg` argument: `cfg(foo,values(bar))`
+fn main() {}
@@ -0,0 +1,17 @@
+// Check warning for unexpected configuration value
+//
+// check-pass
+// compile-flags: --check-cfg=cfg(feature,values("serde","full")) -Z unstable-options
+
+#[cfg(feature = "sedre")]
+//~^ WARNING unexpected `cfg` condition value
+pub fn f() {}
+
+#[cfg(feature = "serde")]
+pub fn g() {}
+
+#[cfg(feature)]
+//~^ WARNING unexpected `cfg` condition value
+pub fn h() {}
+
+pub fn main() {}
@@ -0,0 +1,3 @@
+// compile-flags: --check-cfg=foo -Z unstable-options
+// error-pattern: invalid `--check-cfg` argument: `foo` (expected `cfg(name, values("value"))`)
+fn main() {}
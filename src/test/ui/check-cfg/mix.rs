@@ -0,0 +1,33 @@
+// This test checks that `--check-cfg` is also applied to `cfg!` and `#[cfg_attr]`, and to
+// every predicate of `any()` and `all()`.
+//
+// check-pass
+// compile-flags: --check-cfg=cfg(foo) --check-cfg=cfg(feature,values("foo")) -Z unstable-options
+
+#[cfg(foo)]
+fn do_foo_stuff() {}
+
+#[cfg(fooo)]
+//~^ WARNING unexpected `cfg` condition name
+fn do_foo_stuff() {}
+
+#[cfg_attr(uu, test)]
+//~^ WARNING unexpected `cfg` condition name
+fn do_test() {}
+
+#[cfg(feature = "foo")]
+fn use_foo() {}
+
+#[cfg(feature = "bar")]
+//~^ WARNING unexpected `cfg` condition value
+fn use_bar() {}
+
+fn main() {
+    if cfg!(target_os = "linux") {}
+    if cfg!(xxx = "foo") {}
+    //~^ WARNING unexpected `cfg` condition name
+    if cfg!(all(foo, feature = "zebra")) {}
+    //~^ WARNING unexpected `cfg` condition value
+    if cfg!(any(unix, windows, foo = "x")) {}
+    //~^ WARNING unexpected `cfg` condition value
+}
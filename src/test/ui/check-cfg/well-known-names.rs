@@ -0,0 +1,25 @@
+// This test checks that the cfg names set by the compiler are known to `--check-cfg`
+//
+// check-pass
+// compile-flags: --check-cfg=cfg() -Z unstable-options
+
+#[cfg(target_feature = "sse2")]
+fn with_sse2() {}
+
+#[cfg(any(target_os = "linux", target_family = "unix", target_arch = "x86_64"))]
+fn target() {}
+
+#[cfg(any(target_endian = "little", target_env = "gnu", target_pointer_width = "64"))]
+fn target_details() {}
+
+#[cfg(any(target_vendor = "unknown", unix, windows))]
+fn target_vendor() {}
+
+#[cfg(any(debug_assertions, test, doc, doctest, miri, proc_macro))]
+fn session() {}
+
+#[cfg(widnows)]
+//~^ WARNING unexpected `cfg` condition name
+fn unknown() {}
+
+fn main() {}
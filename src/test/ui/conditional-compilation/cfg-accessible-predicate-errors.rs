@@ -0,0 +1,20 @@
+#![feature(cfg_accessible)]
+
+mod m {
+    pub struct Existing;
+}
+
+#[cfg(accessible())] //~ ERROR `cfg_accessible` path is not specified
+struct NoPath;
+
+#[cfg(accessible(m::Existing, m::Existing))] //~ ERROR multiple `cfg_accessible` paths are specified
+struct MultiplePaths;
+
+#[cfg_attr(accessible(m::Existing), derive(Debug))]
+//~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+struct CfgAttr;
+
+fn main() {
+    cfg!(accessible(m::Existing));
+    //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+}
@@ -0,0 +1,39 @@
+#![feature(cfg_accessible, stmt_expr_attributes)]
+
+mod m {
+    pub struct Existing;
+}
+
+struct Field {
+    #[cfg(accessible(m::Existing))]
+    //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+    field: u8,
+}
+
+enum Variant {
+    #[cfg(accessible(m::Existing))]
+    //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+    Variant,
+}
+
+fn param(#[cfg(accessible(m::Existing))] _param: u8) {}
+//~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+
+fn main() {
+    match 0 {
+        #[cfg(accessible(m::Existing))]
+        //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+        0 => {}
+        _ => {}
+    }
+
+    #[cfg(accessible(m::Existing))]
+    //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+    let _statement = 0;
+
+    let _expression = (#[cfg(accessible(m::Existing))] 0, 1);
+    //~^ ERROR `accessible` predicates are only supported in `#[cfg]` attributes on items
+
+    let _required = #[cfg(accessible(m::Existing))] 0;
+    //~^ ERROR removing an expression is not supported in this position
+}
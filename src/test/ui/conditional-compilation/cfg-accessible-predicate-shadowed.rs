@@ -0,0 +1,27 @@
+// `#[cfg(accessible(..))]` is expanded by a built-in that is not looked up by name,
+// so other `cfg_accessible` macros in scope or a missing prelude don't affect it.
+
+// check-pass
+// edition:2018
+
+#![feature(cfg_accessible)]
+
+use core::prelude::v1::test as cfg_accessible;
+
+mod m {
+    pub struct Existing;
+}
+
+#[cfg(accessible(m::Existing))]
+struct Shadowed;
+
+#[no_implicit_prelude]
+mod no_prelude {
+    #[cfg(accessible(crate::m::Existing))]
+    pub struct NoPrelude;
+}
+
+fn main() {
+    let _ = Shadowed;
+    let _ = no_prelude::NoPrelude;
+}
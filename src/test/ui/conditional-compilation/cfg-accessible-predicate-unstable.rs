@@ -0,0 +1,4 @@
+#[cfg(accessible(std))] //~ ERROR `cfg(accessible)` is experimental and subject to change
+struct S;
+
+fn main() {}
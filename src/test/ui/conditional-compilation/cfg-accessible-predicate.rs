@@ -0,0 +1,69 @@
+// check-pass
+
+#![feature(cfg_accessible)]
+
+mod m {
+    pub struct ExistingPublic;
+    struct ExistingPrivate;
+}
+
+#[cfg(accessible(m::ExistingPublic))]
+struct ExistingPublic;
+
+#[cfg(accessible(m::ExistingPrivate))]
+struct ExistingPrivate;
+
+#[cfg(not(accessible(m::NonExistent)))]
+struct NonExistent;
+
+#[cfg(all(accessible(m::ExistingPublic), not(accessible(m::ExistingPrivate))))]
+struct All;
+
+#[cfg(any(accessible(m::NonExistent), accessible(m::ExistingPrivate)))]
+struct Any;
+
+#[cfg(any(accessible(m::NonExistent), unix, not(unix)))]
+struct Mixed;
+
+#[cfg(accessible(n::AccessibleExpanded))] // OK, the predicate can wait and retry.
+struct AccessibleExpanded;
+
+macro_rules! generate_accessible_expanded {
+    () => {
+        mod n {
+            pub struct AccessibleExpanded;
+        }
+    };
+}
+
+generate_accessible_expanded!();
+
+#[derive(Clone)]
+#[cfg(accessible(m::ExistingPublic))]
+struct AfterDerive;
+
+trait Trait {
+    #[cfg(accessible(m::ExistingPublic))]
+    fn trait_item() {}
+}
+
+impl Trait for AfterDerive {}
+
+struct ExistingPrivate;
+struct Any;
+
+fn main() {
+    ExistingPublic;
+    ExistingPrivate;
+    NonExistent;
+    All;
+    Any;
+    Mixed;
+    AccessibleExpanded;
+    AfterDerive.clone();
+    AfterDerive::trait_item();
+
+    #[cfg(accessible(m::ExistingPublic))]
+    fn statement_item() {}
+    statement_item();
+}
@@ -8,13 +8,14 @@ mod m {
 #[cfg_accessible(m::ExistingPublic)]
 struct ExistingPublic;
 
-// FIXME: Not implemented yet.
-#[cfg_accessible(m::ExistingPrivate)] //~ ERROR not sure whether the path is accessible or not
+#[cfg_accessible(m::ExistingPrivate)]
 struct ExistingPrivate;
 
-// FIXME: Not implemented yet.
-#[cfg_accessible(m::NonExistent)] //~ ERROR not sure whether the path is accessible or not
-struct ExistingPrivate;
+#[cfg_accessible(m::NonExistent)]
+struct NonExistent;
+
+#[cfg_accessible(n::NonExistent)]
+struct NonExistentExpanded;
 
 #[cfg_accessible(n::AccessibleExpanded)] // OK, `cfg_accessible` can wait and retry.
 struct AccessibleExpanded;
@@ -37,6 +38,10 @@ struct S {
 #[cfg_accessible(S::field)] //~ ERROR not sure whether the path is accessible or not
 struct Field;
 
+struct ExistingPrivate;
+struct NonExistent;
+struct NonExistentExpanded;
+
 fn main() {
     ExistingPublic;
     AccessibleExpanded;
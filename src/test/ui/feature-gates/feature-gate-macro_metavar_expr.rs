@@ -0,0 +1,10 @@
+#![allow(unused_macros)]
+
+macro_rules! count {
+    ( $( $i:ident ),* ) => {
+        ${count(i)}
+        //~^ ERROR meta-variable expressions are unstable
+    };
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![deny(fuzzy_provenance_casts)]
+//~^ ERROR the `fuzzy_provenance_casts` lint is unstable
+#![deny(lossy_provenance_casts)]
+//~^ ERROR the `lossy_provenance_casts` lint is unstable
+
+fn main() {
+    // no warnings emitted since the lints are not activated
+
+    let _dangling = 16_usize as *const u8;
+
+    let x: u8 = 37;
+    let _addr: usize = &x as *const u8 as usize;
+}
@@ -0,0 +1,45 @@
+#![deny(dead_code, fields_only_read_by_derives)]
+
+#[derive(Debug, Clone)]
+struct A {
+    f: (), //~ ERROR: field is only read by derived impls: `f`
+}
+
+#[derive(Debug)]
+struct B {
+    f: (), //~ ERROR: field is only read by derived impls: `f`
+}
+
+// Reads outside of derived impls still count.
+#[derive(Debug)]
+struct C {
+    f: (),
+}
+
+// Only the derived impls of traits like `Debug` and `Clone` are ignored.
+#[derive(PartialEq)]
+struct D {
+    f: (),
+}
+
+#[derive(Debug)]
+struct E(i32); //~ ERROR: field is only read by derived impls: `0`
+
+#[derive(Debug)]
+struct F {
+    #[allow(fields_only_read_by_derives)]
+    f: (),
+}
+
+fn main() {
+    let a = A { f: () };
+    println!("{:?}", a.clone());
+    let b = B { f: () };
+    println!("{:?}", b);
+    let c = C { f: () };
+    let _ = c.f;
+    let d = D { f: () };
+    let _ = d == d;
+    println!("{:?}", E(0));
+    println!("{:?}", F { f: () });
+}
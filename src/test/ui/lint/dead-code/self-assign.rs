@@ -1,8 +1,6 @@
 // Test that dead code warnings are issued for superfluous assignments of
 // fields or variables to themselves (issue #75356).
 
-// ignore-test FIXME(81658, 83171)
-
 // check-pass
 #![allow(unused_assignments)]
 #![warn(dead_code)]
@@ -49,4 +47,9 @@ macro_rules! assign_to_y {
     }
     assign_to_y!(y);
     // self-assignments in macro expansions are not reported either
+
+    let cell = std::cell::RefCell::new(S { f: "abc" });
+    let mut guard = cell.borrow_mut();
+    guard.f = guard.f;
+    // assignments through an overloaded `Deref` may not be useless and are not reported
 }
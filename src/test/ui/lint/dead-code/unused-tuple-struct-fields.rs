@@ -0,0 +1,32 @@
+#![deny(unused_tuple_struct_fields)]
+
+const LEN: usize = 4;
+
+struct SingleUnused(i32, [u8; LEN], String);
+//~^ ERROR: field is never read: `1`
+struct MultipleUnused(i32, f32, String, u8);
+//~^ ERROR: field is never read: `0`
+//~| ERROR: field is never read: `1`
+//~| ERROR: field is never read: `2`
+//~| ERROR: field is never read: `3`
+struct Matched(i32, u8, char);
+//~^ ERROR: field is never read: `1`
+
+struct GoodUnit(());
+
+struct GoodPhantom(std::marker::PhantomData<i32>);
+
+fn main() {
+    let w = SingleUnused(42, [0, 1, 2, 3], "abc".to_string());
+    let _ = w.0;
+    let _ = w.2;
+
+    let m = MultipleUnused(42, 3.14, "def".to_string(), 4u8);
+
+    let Matched(first, .., last) = Matched(1, 2, 'c');
+
+    let gu = GoodUnit(());
+    let gp = GoodPhantom(std::marker::PhantomData);
+
+    let _ = (m, first, last, gu, gp);
+}
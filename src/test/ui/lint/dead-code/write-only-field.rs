@@ -0,0 +1,36 @@
+#![deny(dead_code, unused_tuple_struct_fields)]
+
+struct S(i32, Sub); //~ ERROR: field is never read: `0`
+//~^ ERROR: field is never read: `1`
+
+struct Sub(i32); //~ ERROR: field is never read: `0`
+
+struct Counter(i32);
+
+struct Named {
+    f: i32, //~ ERROR: field is never read: `f`
+    sub: NamedSub, //~ ERROR: field is never read: `sub`
+    read: i32,
+}
+
+struct NamedSub {
+    g: i32, //~ ERROR: field is never read: `g`
+}
+
+fn field_write(s: &mut S, n: &mut Named) {
+    s.0 = 1;
+    (s.1).0 = 2;
+    n.f = 3;
+    n.sub.g = 4;
+    let _ = n.read;
+}
+
+fn main() {
+    let mut s = S(0, Sub(0));
+    let mut n = Named { f: 0, sub: NamedSub { g: 0 }, read: 0 };
+    field_write(&mut s, &mut n);
+
+    // Compound assignments read the field.
+    let mut c = Counter(0);
+    c.0 += 1;
+}
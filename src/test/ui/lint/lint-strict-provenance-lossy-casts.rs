@@ -0,0 +1,15 @@
+#![feature(strict_provenance)]
+#![deny(lossy_provenance_casts)]
+
+fn main() {
+    let x: u8 = 37;
+    let addr: usize = &x as *const u8 as usize;
+    //~^ ERROR bad style to cast pointer `*const u8` to integer `usize`
+
+    let addr_32bit = &x as *const u8 as u32;
+    //~^ ERROR bad style to cast pointer `*const u8` to integer `u32`
+
+    let ptr = &x as *const u8;
+    let addr_from_binding = ptr as usize;
+    //~^ ERROR bad style to cast pointer `*const u8` to integer `usize`
+}
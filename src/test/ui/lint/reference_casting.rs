@@ -0,0 +1,37 @@
+use std::cell::UnsafeCell;
+use std::mem;
+use std::ptr;
+
+unsafe fn assign_to_ref(a: &i32) {
+    *(a as *const i32 as *mut i32) = 1;
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+    *((a as *const i32).cast::<u32>() as *mut u32) += 1;
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+    ptr::write(a as *const i32 as *mut i32, 2);
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+    *mem::transmute::<&i32, *mut i32>(a) = 3;
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+
+    let p = a as *const i32 as *mut i32;
+    p.write_volatile(4);
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+
+    let ptr = a as *const i32;
+    let mut_ptr = ptr as *mut i32;
+    *mut_ptr = 5;
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+    let r = &mut *mut_ptr;
+    *r = 6;
+    //~^ ERROR writing through a pointer that was cast from a shared reference
+}
+
+unsafe fn no_lint(a: &UnsafeCell<i32>, b: &mut i32, c: &i32) {
+    *(a as *const UnsafeCell<i32> as *mut i32) = 1;
+    *(b as *const i32 as *mut i32) = 2;
+    let mut p = c as *const i32 as *mut i32;
+    p = b;
+    *p = 3;
+    let _ = *(c as *const i32 as *mut i32);
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// check-pass
+
+#![feature(lint_reasons)]
+#![warn(dead_code)]
+
+// Like with `allow`, the items used by an item expecting `dead_code` are live, so `b`
+// isn't reported, while `a` is still reported and fulfills the expectation.
+#[expect(dead_code)]
+fn a() {
+    b()
+}
+
+fn b() {}
+
+fn main() {}
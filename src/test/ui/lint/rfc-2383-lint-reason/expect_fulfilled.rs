@@ -0,0 +1,39 @@
+// check-pass
+
+#![feature(lint_reasons)]
+#![warn(unused)]
+
+#[expect(unused_variables)]
+fn check_specific_lint() {
+    let x = 2;
+}
+
+#[expect(unused)]
+fn check_lint_group() {
+    let x = 15;
+}
+
+#[expect(unused_variables, reason = "`x` is only used on some platforms")]
+fn check_expectation_with_reason() {
+    let x = 3;
+}
+
+fn check_expectation_on_statement() {
+    #[expect(unused_variables)]
+    let x = 4;
+}
+
+#[expect(dead_code)]
+fn check_dead_code() {}
+
+// Like with `allow`, lints of unknown tools are ignored, and so are their expectations.
+#[expect(clippy::almost_swapped)]
+fn check_unknown_tool_lint() {}
+
+fn main() {
+    check_specific_lint();
+    check_lint_group();
+    check_expectation_with_reason();
+    check_expectation_on_statement();
+    check_unknown_tool_lint();
+}
@@ -0,0 +1,15 @@
+// build-pass
+
+#![feature(lint_reasons, large_assignments)]
+#![move_size_limit = "1000"]
+#![warn(large_assignments)]
+
+// `large_assignments` is only emitted while collecting the mono items,
+// so the expectation must not be checked at the end of the analysis.
+#[expect(large_assignments)]
+fn main() {
+    let x = [0u8; 9999];
+    take(x);
+}
+
+fn take(_: [u8; 9999]) {}
@@ -0,0 +1,34 @@
+// check-pass
+
+#![feature(lint_reasons)]
+#![warn(unused)]
+
+#[expect(unused_variables)]
+//~^ WARNING this lint expectation is unfulfilled [unfulfilled_lint_expectations]
+//~| NOTE `#[warn(unfulfilled_lint_expectations)]` on by default
+fn check_unfulfilled_expectation() {
+    let x = 1;
+    println!("{}", x);
+}
+
+#[expect(unused_mut, reason = "`x` used to be mutated")]
+//~^ WARNING this lint expectation is unfulfilled [unfulfilled_lint_expectations]
+//~| NOTE `x` used to be mutated
+fn check_unfulfilled_expectation_with_reason() {
+    let mut x = 1;
+    x += 1;
+    println!("{}", x);
+}
+
+#[expect(unused_variables)]
+#[allow(unfulfilled_lint_expectations)]
+fn check_allowed_unfulfilled_expectation() {
+    let x = 1;
+    println!("{}", x);
+}
+
+fn main() {
+    check_unfulfilled_expectation();
+    check_unfulfilled_expectation_with_reason();
+    check_allowed_unfulfilled_expectation();
+}
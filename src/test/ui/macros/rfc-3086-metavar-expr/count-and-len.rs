@@ -0,0 +1,45 @@
+// run-pass
+
+#![feature(macro_metavar_expr)]
+
+macro_rules! count_idents {
+    ( $( $i:ident ),* ) => {
+        ${count(i)}
+    };
+}
+
+macro_rules! count_nested {
+    ( $( [ $( $i:ident ),* ] )* ) => {
+        [ ${count(i, 0)}, ${count(i, 1)}, ${count(i)} ]
+    };
+}
+
+macro_rules! count_inner {
+    ( $( [ $( $i:ident ),* ] )* ) => {
+        [ $( ${ignore(i)} ${count(i)}, )* ]
+    };
+}
+
+macro_rules! indices {
+    ( $( $i:ident ),* ) => {
+        [ $( (${index()}, ${len()}, ${ignore(i)}) ),* ]
+    };
+}
+
+macro_rules! nested_indices {
+    ( $( [ $( $i:ident ),* ] )* ) => {
+        [ $( $( (${index(1)}, ${index()}, ${len(1)}, ${len()}, ${ignore(i)}) ),* ),* ]
+    };
+}
+
+fn main() {
+    assert_eq!(count_idents!(), 0);
+    assert_eq!(count_idents!(a, b, c), 3);
+    assert_eq!(count_nested!([a, b, c] [d]), [2, 4, 4]);
+    assert_eq!(count_inner!([a, b] [c] []), [2, 1, 0]);
+    assert_eq!(indices!(a, b, c), [(0, 3), (1, 3), (2, 3)]);
+    assert_eq!(
+        nested_indices!([a, b] [c]),
+        [(0, 0, 2, 2), (0, 1, 2, 2), (1, 0, 2, 1)]
+    );
+}
@@ -0,0 +1,12 @@
+#![feature(macro_metavar_expr)]
+
+macro_rules! count_outer {
+    ( $( $a:ident )* ; $( [ $( $x:ident )* ] )* ) => {
+        $( ${count(x)} $a )*
+        //~^ ERROR meta-variable repeats fewer times than the repetition `count` is placed in
+    };
+}
+
+fn main() {
+    count_outer!(a b c ; [d]);
+}
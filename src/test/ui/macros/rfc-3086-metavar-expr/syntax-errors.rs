@@ -0,0 +1,24 @@
+#![feature(macro_metavar_expr)]
+#![allow(unused_macros)]
+
+macro_rules! unknown_expr {
+    ( $( $i:ident ),* ) => { ${foo(i)} };
+    //~^ ERROR unrecognized meta-variable expression
+}
+
+macro_rules! no_parens {
+    ( $( $i:ident ),* ) => { ${count} };
+    //~^ ERROR meta-variable expression parameter must be wrapped in parentheses
+}
+
+macro_rules! trailing_tokens {
+    ( $( $i:ident ),* ) => { ${ignore(i) extra} };
+    //~^ ERROR unexpected token: extra
+}
+
+macro_rules! suffixed_depth {
+    ( $( $i:ident ),* ) => { $( ${ignore(i)} ${index(1u8)} )* };
+    //~^ ERROR only unsuffixed integer literals are supported in meta-variable expressions
+}
+
+fn main() {}
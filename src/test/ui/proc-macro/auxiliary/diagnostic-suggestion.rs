@@ -0,0 +1,32 @@
+// force-host
+// no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro_diagnostic)]
+
+extern crate proc_macro;
+
+use proc_macro::{Applicability, TokenStream, TokenTree};
+
+#[proc_macro]
+pub fn no_shouting(input: TokenStream) -> TokenStream {
+    for tree in input {
+        if let TokenTree::Ident(ref ident) = tree {
+            let name = ident.to_string();
+            if name.chars().all(|c| c.is_ascii_uppercase()) {
+                ident
+                    .span()
+                    .error(format!("identifier `{}` is shouting", name))
+                    .span_suggestion(
+                        ident.span(),
+                        "use lowercase",
+                        name.to_ascii_lowercase(),
+                        Applicability::MachineApplicable,
+                    )
+                    .emit();
+            }
+        }
+    }
+
+    TokenStream::new()
+}
@@ -43,3 +43,18 @@ pub fn macro_stringify(input: TokenStream) -> TokenStream {
     let src = span.source_text().expect("source_text");
     TokenTree::Literal(Literal::string(&src)).into()
 }
+
+#[proc_macro]
+pub fn assert_byte_range(input: TokenStream) -> TokenStream {
+    for tk in input {
+        let span = tk.span();
+        assert_eq!(span.line(), span.start().line);
+        assert_eq!(span.column(), span.start().column);
+        let path = span.source_file().path();
+        let src = std::fs::read_to_string(&path).expect("source file");
+        let text = span.source_text().expect("source_text");
+        assert_eq!(&src[span.byte_range()], text);
+    }
+
+    "".parse().unwrap()
+}
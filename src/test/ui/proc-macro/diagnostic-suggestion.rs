@@ -0,0 +1,10 @@
+// aux-build:diagnostic-suggestion.rs
+
+extern crate diagnostic_suggestion;
+
+use diagnostic_suggestion::no_shouting;
+
+fn main() {
+    no_shouting!(hello world);
+    no_shouting!(hello WORLD); //~ ERROR identifier `WORLD` is shouting
+}
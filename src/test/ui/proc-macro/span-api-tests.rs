@@ -10,7 +10,7 @@
 
 // FIXME(69775): Investigate `assert_fake_source_file`.
 
-use span_api_tests::{reemit, assert_source_file, macro_stringify};
+use span_api_tests::{reemit, assert_byte_range, assert_source_file, macro_stringify};
 
 macro_rules! say_hello {
     ($macname:ident) => ( $macname! { "Hello, world!" })
@@ -30,6 +30,8 @@ macro_rules! say_hello {
     assert_source_file! { "Hello, world!" }
 }
 
+assert_byte_range! { "Hello, world!" fn (a, b) { "Ünïcödé" } }
+
 fn main() {
     let s = macro_stringify!(Hello, world!);
     assert_eq!(s, "Hello, world!");
@@ -0,0 +1,63 @@
+// Test that the `non_exhaustive_omitted_patterns` lint is triggered correctly.
+
+// aux-build:enums.rs
+extern crate enums;
+
+use enums::NonExhaustiveEnum;
+
+fn main() {
+    let enumeration = NonExhaustiveEnum::Unit;
+
+    #[deny(non_exhaustive_omitted_patterns)]
+    match enumeration {
+        NonExhaustiveEnum::Unit => {}
+        NonExhaustiveEnum::Tuple(_) => {}
+        _ => {}
+    }
+    //~^^ ERROR some variants are not matched explicitly
+
+    #[deny(non_exhaustive_omitted_patterns)]
+    match enumeration {
+        NonExhaustiveEnum::Unit => {}
+        _ => {}
+    }
+    //~^^ ERROR some variants are not matched explicitly
+
+    // All the variants are matched explicitly, so the wildcard doesn't omit any of them.
+    #[deny(non_exhaustive_omitted_patterns)]
+    match enumeration {
+        NonExhaustiveEnum::Unit => {}
+        NonExhaustiveEnum::Tuple(_) => {}
+        NonExhaustiveEnum::Struct { .. } => {}
+        _ => {}
+    }
+
+    // The lint is allowed by default.
+    match enumeration {
+        NonExhaustiveEnum::Unit => {}
+        _ => {}
+    }
+
+    match enumeration {
+        NonExhaustiveEnum::Unit | NonExhaustiveEnum::Tuple(_) => {}
+        #[deny(non_exhaustive_omitted_patterns)]
+        _ => {}
+    }
+    //~^^ ERROR some variants are not matched explicitly
+
+    let x = Some(NonExhaustiveEnum::Unit);
+    #[deny(non_exhaustive_omitted_patterns)]
+    match x {
+        Some(NonExhaustiveEnum::Unit) => {}
+        Some(_) => {}
+        None => {}
+    }
+    //~^^^ ERROR some variants are not matched explicitly
+
+    // A wildcard that isn't preceded by any variant doesn't omit variants on purpose.
+    #[deny(non_exhaustive_omitted_patterns)]
+    let _ = enumeration;
+
+    #[deny(non_exhaustive_omitted_patterns)]
+    if let NonExhaustiveEnum::Unit = enumeration {}
+}
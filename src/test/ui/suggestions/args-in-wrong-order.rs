@@ -0,0 +1,17 @@
+// Check that we suggest reordering the arguments of a call when all of their types would match
+// in a different order.
+
+fn two_args(_a: u32, _b: &str) {}
+
+struct S;
+
+impl S {
+    fn three_args(&self, _a: u32, _b: &str, _c: bool) {}
+}
+
+fn main() {
+    two_args("", 1);
+    //~^ ERROR arguments to this function are passed in the wrong order
+    S.three_args(true, 1, "");
+    //~^ ERROR arguments to this method are passed in the wrong order
+}
@@ -0,0 +1,24 @@
+// run-rustfix
+// rustfix-only-machine-applicable
+
+// Check that the suggestion to remove an unused parameter also removes the `where` clause
+// predicates bounding it.
+
+#![allow(dead_code)]
+
+struct Single<T> where T: Copy { x: u32 }
+//~^ ERROR parameter `T` is never used
+
+struct First<T, U> where T: Copy, U: Clone { u: U }
+//~^ ERROR parameter `T` is never used
+
+struct Last<U, T> where U: Clone, T: Copy { u: U }
+//~^ ERROR parameter `T` is never used
+
+struct Twice<T, U> where T: Copy, U: Clone, T: Default { u: U }
+//~^ ERROR parameter `T` is never used
+
+struct Region<'a, 'b> where 'b: 'a { x: &'a u32 }
+//~^ ERROR parameter `'b` is never used
+
+fn main() {}
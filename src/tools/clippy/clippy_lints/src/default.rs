@@ -1,11 +1,12 @@
 use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_sugg};
+use clippy_utils::eager_or_lazy::is_eagerness_candidate;
 use clippy_utils::source::snippet_with_macro_callsite;
 use clippy_utils::{any_parent_is_automatically_derived, contains_name, in_macro, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
-use rustc_hir::def::Res;
-use rustc_hir::{Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind};
+use rustc_hir::def::{CtorKind, Res};
+use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -41,6 +42,10 @@
     /// ### Why is this bad?
     ///It's more idiomatic to use the [functional update syntax](https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax).
     ///
+    /// For tuple structs, the suggestion uses the tuple constructor when every field is
+    /// reassigned, as long as the values can be put in field order without reordering
+    /// their side effects.
+    ///
     /// ### Known problems
     /// Assignments to patterns that are of tuple type are not linted.
     ///
@@ -119,7 +124,7 @@ fn check_block<'tcx>(&mut self, cx: &LateContext<'tcx>, block: &Block<'tcx>) {
             // find all binding statements like `let mut _ = T::default()` where `T::default()` is the
             // `default` method of the `Default` trait, and store statement index in current block being
             // checked and the name of the bound variable
-            let (local, variant, binding_name, binding_type, span) = if_chain! {
+            let (local, variant, binding_id, binding_name, binding_type, span) = if_chain! {
                 // only take `let ...` statements
                 if let StmtKind::Local(local) = stmt.kind;
                 if let Some(expr) = local.init;
@@ -140,7 +145,7 @@ fn check_block<'tcx>(&mut self, cx: &LateContext<'tcx>, block: &Block<'tcx>) {
                     .iter()
                     .all(|field| field.vis.is_accessible_from(module_did, cx.tcx));
                 then {
-                    (local, variant, ident.name, binding_type, expr.span)
+                    (local, variant, binding_id, ident.name, binding_type, expr.span)
                 } else {
                     continue;
                 }
@@ -153,7 +158,7 @@ fn check_block<'tcx>(&mut self, cx: &LateContext<'tcx>, block: &Block<'tcx>) {
             let mut cancel_lint = false;
             for consecutive_statement in &block.stmts[stmt_idx + 1..] {
                 // find out if and which field was set by this `consecutive_statement`
-                if let Some((field_ident, assign_rhs)) = field_reassigned_by_stmt(consecutive_statement, binding_name) {
+                if let Some((field_ident, assign_rhs)) = field_reassigned_by_stmt(consecutive_statement, binding_id) {
                     // interrupt and cancel lint if assign_rhs references the original binding
                     if contains_name(binding_name, assign_rhs) {
                         cancel_lint = true;
@@ -190,12 +195,33 @@ fn check_block<'tcx>(&mut self, cx: &LateContext<'tcx>, block: &Block<'tcx>) {
                     .iter()
                     .all(|field| assigned_fields.iter().any(|(a, _)| a == &field.ident.name));
 
+                // tuple structs with all of their fields reassigned can use the constructor instead of
+                // numbered fields, e.g. `T(1, 2)` rather than `T { 0: 1, 1: 2 }`, unless putting the
+                // values in field order changes the order in which their side effects happen
+                let field_index = |field: &Symbol| field.as_str().parse::<usize>().unwrap_or(usize::MAX);
+                let in_order = assigned_fields
+                    .windows(2)
+                    .all(|w| field_index(&w[0].0) < field_index(&w[1].0));
+                let mut with_side_effects = assigned_fields
+                    .iter()
+                    .filter(|(_, rhs)| !is_eagerness_candidate(cx, rhs));
+                let use_tuple_ctor = !ext_with_default
+                    && variant.ctor_kind == CtorKind::Fn
+                    && (in_order || with_side_effects.nth(1).is_none());
+                if use_tuple_ctor {
+                    assigned_fields.sort_by_key(|(field, _)| field_index(field));
+                }
+
                 let field_list = assigned_fields
                     .into_iter()
                     .map(|(field, rhs)| {
                         // extract and store the assigned value for help message
                         let value_snippet = snippet_with_macro_callsite(cx, rhs.span, "..");
-                        format!("{}: {}", field, value_snippet)
+                        if use_tuple_ctor {
+                            value_snippet.into_owned()
+                        } else {
+                            format!("{}: {}", field, value_snippet)
+                        }
                     })
                     .collect::<Vec<String>>()
                     .join(", ");
@@ -224,6 +250,8 @@ fn check_block<'tcx>(&mut self, cx: &LateContext<'tcx>, block: &Block<'tcx>) {
                     } else {
                         format!("{} {{ {}, ..Default::default() }}", binding_type, field_list)
                     }
+                } else if use_tuple_ctor {
+                    format!("{}({})", binding_type, field_list)
                 } else {
                     format!("{} {{ {} }}", binding_type, field_list)
                 };
@@ -262,17 +290,16 @@ fn is_expr_default<'tcx>(expr: &'tcx Expr<'tcx>, cx: &LateContext<'tcx>) -> bool
 }
 
 /// Returns the reassigned field and the assigning expression (right-hand side of assign).
-fn field_reassigned_by_stmt<'tcx>(this: &Stmt<'tcx>, binding_name: Symbol) -> Option<(Ident, &'tcx Expr<'tcx>)> {
+fn field_reassigned_by_stmt<'tcx>(this: &Stmt<'tcx>, binding_id: HirId) -> Option<(Ident, &'tcx Expr<'tcx>)> {
     if_chain! {
         // only take assignments
         if let StmtKind::Semi(later_expr) = this.kind;
         if let ExprKind::Assign(assign_lhs, assign_rhs, _) = later_expr.kind;
         // only take assignments to fields where the left-hand side field is a field of
-        // the same binding as the previous statement
+        // the same binding as the previous statement, named or numbered (`a.i = ..`, `a.0 = ..`)
         if let ExprKind::Field(binding, field_ident) = assign_lhs.kind;
         if let ExprKind::Path(QPath::Resolved(_, path)) = binding.kind;
-        if let Some(second_binding_name) = path.segments.last();
-        if second_binding_name.ident.name == binding_name;
+        if path.res == Res::Local(binding_id);
         then {
             Some((field_ident, assign_rhs))
         } else {
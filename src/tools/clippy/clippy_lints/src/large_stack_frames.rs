@@ -0,0 +1,153 @@
+use std::cmp::Reverse;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::fn_has_unsatisfiable_preds;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::mir::VarDebugInfoContents;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions that use a lot of stack space.
+    ///
+    /// This often happens when constructing a large type, such as an array with a lot of elements,
+    /// or constructing *many* smaller-but-still-large values. It is a more general version of
+    /// `large_stack_arrays` that looks at a function as a whole instead of individual expressions.
+    ///
+    /// ### Why is this bad?
+    /// The stack is very limited in size (usually *much* smaller than the heap), and using too
+    /// much of it will overflow the stack and crash the program. Embedded targets and deeply
+    /// recursive code are especially sensitive to this.
+    ///
+    /// Note that the code constructing a large value does not even need to be reachable; it only
+    /// needs to *exist* inside of the function to contribute to its stack frame.
+    ///
+    /// ### Known problems
+    /// The stack size is only an estimate: it is the sum of the sizes of all locals in the
+    /// function's MIR, including temporaries, arguments and the return place. Register spills are
+    /// not accounted for, and optimizations may reuse or remove a lot of that space, especially
+    /// in release mode.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn foo() {
+    ///     // Each of these is smaller than the `large_stack_arrays` threshold,
+    ///     // but together they use 2 MB of stack space.
+    ///     let x1 = [0u8; 500_000];
+    ///     let x2 = [0u8; 500_000];
+    ///     let x3 = [0u8; 500_000];
+    ///     let x4 = [0u8; 500_000];
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn foo() {
+    ///     let x1 = vec![0u8; 500_000].into_boxed_slice();
+    ///     let x2 = vec![0u8; 500_000].into_boxed_slice();
+    ///     let x3 = vec![0u8; 500_000].into_boxed_slice();
+    ///     let x4 = vec![0u8; 500_000].into_boxed_slice();
+    /// }
+    /// ```
+    pub LARGE_STACK_FRAMES,
+    nursery,
+    "checks for functions that allocate a lot of stack space"
+}
+
+/// The number of locals pointed at when a function's stack frame is too large.
+const MAX_LABELED_LOCALS: usize = 3;
+
+pub struct LargeStackFrames {
+    maximum_allowed_size: u64,
+}
+
+impl LargeStackFrames {
+    #[must_use]
+    pub fn new(maximum_allowed_size: u64) -> Self {
+        Self { maximum_allowed_size }
+    }
+}
+
+impl_lint_pass!(LargeStackFrames => [LARGE_STACK_FRAMES]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeStackFrames {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: HirId,
+    ) {
+        if in_external_macro(cx.sess(), span) {
+            return;
+        }
+
+        let def_id = cx.tcx.hir().body_owner_def_id(body.id()).to_def_id();
+
+        // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+        if fn_has_unsatisfiable_preds(cx, def_id) {
+            return;
+        }
+
+        let mir = cx.tcx.optimized_mir(def_id);
+        let param_env = cx.tcx.param_env(def_id);
+
+        // The size of every local whose layout is known. Locals of generic types are skipped, so
+        // the estimate for generic functions is a lower bound.
+        let sizes = mir
+            .local_decls
+            .iter_enumerated()
+            .filter_map(|(local, decl)| {
+                let layout = cx.tcx.layout_of(param_env.and(decl.ty)).ok()?;
+                Some((local, layout.size.bytes()))
+            })
+            .collect::<Vec<_>>();
+
+        // `None` means that the sum overflowed, which is certainly too large.
+        let frame_size = sizes.iter().try_fold(0_u64, |sum, &(_, size)| sum.checked_add(size));
+        if matches!(frame_size, Some(size) if size <= self.maximum_allowed_size) {
+            return;
+        }
+
+        let names: FxHashMap<_, _> = mir
+            .var_debug_info
+            .iter()
+            .filter_map(|info| match info.value {
+                VarDebugInfoContents::Place(place) => Some((place.as_local()?, info.name)),
+                VarDebugInfoContents::Const(_) => None,
+            })
+            .collect();
+        let mut largest_locals = sizes
+            .into_iter()
+            .filter_map(|(local, size)| {
+                let name = *names.get(&local)?;
+                let local_span = mir.local_decls[local].source_info.span;
+                (size > 0 && !local_span.from_expansion()).then(|| (name, local_span, size))
+            })
+            .collect::<Vec<_>>();
+        largest_locals.sort_by_key(|&(_, _, size)| Reverse(size));
+
+        span_lint_and_then(
+            cx,
+            LARGE_STACK_FRAMES,
+            cx.sess().source_map().guess_head_span(span),
+            "this function allocates a large amount of stack space",
+            |diag| {
+                for &(name, local_span, size) in largest_locals.iter().take(MAX_LABELED_LOCALS) {
+                    diag.span_label(local_span, format!("`{}` takes up {} bytes", name, size));
+                }
+                diag.note(&format!(
+                    "the estimated stack frame size exceeds the limit of {} bytes",
+                    self.maximum_allowed_size
+                ));
+                diag.note("allocating large amounts of stack space can overflow the stack");
+            },
+        );
+    }
+}
@@ -242,6 +242,7 @@ macro_rules! declare_clippy_lint {
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_stack_arrays;
+mod large_stack_frames;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
@@ -298,6 +299,7 @@ macro_rules! declare_clippy_lint {
 mod non_expressive_names;
 mod non_octal_unix_permissions;
 mod nonstandard_macro_braces;
+mod numbered_fields;
 mod open_options;
 mod option_env_unwrap;
 mod option_if_let_else;
@@ -670,6 +672,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         large_const_arrays::LARGE_CONST_ARRAYS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         large_stack_arrays::LARGE_STACK_ARRAYS,
+        large_stack_frames::LARGE_STACK_FRAMES,
         len_zero::COMPARISON_TO_EMPTY,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
@@ -855,6 +858,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         non_expressive_names::SIMILAR_NAMES,
         non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
         nonstandard_macro_braces::NONSTANDARD_MACRO_BRACES,
+        numbered_fields::INIT_NUMBERED_FIELDS,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         option_env_unwrap::OPTION_ENV_UNWRAP,
         option_if_let_else::OPTION_IF_LET_ELSE,
@@ -883,6 +887,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         ranges::RANGE_ZIP_WITH_LEN,
         ranges::REVERSED_EMPTY_RANGES,
         redundant_clone::REDUNDANT_CLONE,
+        redundant_clone::REDUNDANT_FINAL_CLONE,
         redundant_closure_call::REDUNDANT_CLOSURE_CALL,
         redundant_else::REDUNDANT_ELSE,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
@@ -1378,6 +1383,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(non_expressive_names::JUST_UNDERSCORES_AND_DIGITS),
         LintId::of(non_expressive_names::MANY_SINGLE_CHAR_NAMES),
         LintId::of(non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS),
+        LintId::of(numbered_fields::INIT_NUMBERED_FIELDS),
         LintId::of(open_options::NONSENSICAL_OPEN_OPTIONS),
         LintId::of(option_env_unwrap::OPTION_ENV_UNWRAP),
         LintId::of(overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL),
@@ -1394,6 +1400,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(ranges::RANGE_ZIP_WITH_LEN),
         LintId::of(ranges::REVERSED_EMPTY_RANGES),
         LintId::of(redundant_clone::REDUNDANT_CLONE),
+        LintId::of(redundant_clone::REDUNDANT_FINAL_CLONE),
         LintId::of(redundant_closure_call::REDUNDANT_CLOSURE_CALL),
         LintId::of(redundant_field_names::REDUNDANT_FIELD_NAMES),
         LintId::of(redundant_slicing::REDUNDANT_SLICING),
@@ -1551,6 +1558,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST),
         LintId::of(non_expressive_names::JUST_UNDERSCORES_AND_DIGITS),
         LintId::of(non_expressive_names::MANY_SINGLE_CHAR_NAMES),
+        LintId::of(numbered_fields::INIT_NUMBERED_FIELDS),
         LintId::of(ptr::CMP_NULL),
         LintId::of(ptr::PTR_ARG),
         LintId::of(ptr_eq::PTR_EQ),
@@ -1769,6 +1777,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(misc::CMP_OWNED),
         LintId::of(mutex_atomic::MUTEX_ATOMIC),
         LintId::of(redundant_clone::REDUNDANT_CLONE),
+        LintId::of(redundant_clone::REDUNDANT_FINAL_CLONE),
         LintId::of(slow_vector_initialization::SLOW_VECTOR_INITIALIZATION),
         LintId::of(stable_sort_primitive::STABLE_SORT_PRIMITIVE),
         LintId::of(types::BOX_VEC),
@@ -1792,6 +1801,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         LintId::of(floating_point_arithmetic::IMPRECISE_FLOPS),
         LintId::of(floating_point_arithmetic::SUBOPTIMAL_FLOPS),
         LintId::of(future_not_send::FUTURE_NOT_SEND),
+        LintId::of(large_stack_frames::LARGE_STACK_FRAMES),
         LintId::of(let_if_seq::USELESS_LET_IF_SEQ),
         LintId::of(missing_const_for_fn::MISSING_CONST_FOR_FN),
         LintId::of(mutable_debug_assertion::DEBUG_ASSERT_WITH_MUT_CALL),
@@ -2102,6 +2112,9 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(move || Box::new(disallowed_script_idents::DisallowedScriptIdents::new(&scripts)));
     store.register_late_pass(|| Box::new(strlen_on_c_strings::StrlenOnCStrings));
     store.register_late_pass(move || Box::new(self_named_constructors::SelfNamedConstructors));
+    store.register_late_pass(|| Box::new(numbered_fields::NumberedFields));
+    let stack_size_threshold = conf.stack_size_threshold;
+    store.register_late_pass(move || Box::new(large_stack_frames::LargeStackFrames::new(stack_size_threshold)));
 }
 
 #[rustfmt::skip]
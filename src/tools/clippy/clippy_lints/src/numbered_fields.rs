@@ -0,0 +1,103 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::is_eagerness_candidate;
+use clippy_utils::in_macro;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tuple structs initialized with field syntax.
+    /// It will however not lint if a base initializer is present, if the struct is named
+    /// through a type alias, or if putting the fields in order would change the order in
+    /// which their side effects happen.
+    /// The lint will also ignore code in macros.
+    ///
+    /// ### Why is this bad?
+    /// This may be confusing to the uninitiated and adds no
+    /// benefit as opposed to tuple initializers
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct TupleStruct(u8, u16);
+    ///
+    /// let _ = TupleStruct {
+    ///     0: 1,
+    ///     1: 23,
+    /// };
+    ///
+    /// // should be written as
+    /// let base = TupleStruct(1, 23);
+    ///
+    /// // This is OK however
+    /// let _ = TupleStruct { 0: 42, ..base };
+    /// ```
+    pub INIT_NUMBERED_FIELDS,
+    style,
+    "numbered fields in tuple struct initializer"
+}
+
+declare_lint_pass!(NumberedFields => [INIT_NUMBERED_FIELDS]);
+
+impl<'tcx> LateLintPass<'tcx> for NumberedFields {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if let ExprKind::Struct(path, fields, None) = e.kind {
+            if !fields.is_empty()
+                && !in_macro(e.span)
+                && path_is_ctor(path)
+                && fields
+                    .iter()
+                    .all(|f| f.ident.as_str().as_bytes().iter().all(u8::is_ascii_digit))
+            {
+                let mut indexed: Vec<_> = fields
+                    .iter()
+                    .map(|f| (f.ident.as_str().parse::<usize>().unwrap_or(usize::MAX), f.expr))
+                    .collect();
+                // Reordering the fields would change the order in which the initializers are evaluated,
+                // which only matters if more than one of them may have side effects.
+                let in_order = indexed.windows(2).all(|w| w[0].0 < w[1].0);
+                let mut with_side_effects = indexed.iter().filter(|(_, expr)| !is_eagerness_candidate(cx, expr));
+                if !in_order && with_side_effects.nth(1).is_some() {
+                    return;
+                }
+                let mut appl = Applicability::MachineApplicable;
+                indexed.sort_by_key(|&(idx, _)| idx);
+                let args = indexed
+                    .into_iter()
+                    .map(|(_, expr)| snippet_with_applicability(cx, expr.span, "..", &mut appl))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let snippet = format!(
+                    "{}({})",
+                    snippet_with_applicability(cx, path.span(), "..", &mut appl),
+                    args,
+                );
+                span_lint_and_sugg(
+                    cx,
+                    INIT_NUMBERED_FIELDS,
+                    e.span,
+                    "used a field initializer for a tuple struct",
+                    "try this instead",
+                    snippet,
+                    appl,
+                );
+            }
+        }
+    }
+}
+
+/// Checks that the path of a struct expression also names the tuple constructor, which is not the
+/// case for type aliases: `Alias { 0: .. }` cannot be written as `Alias(..)`.
+fn path_is_ctor(path: &QPath<'_>) -> bool {
+    if let QPath::Resolved(_, path) = path {
+        matches!(
+            path.res,
+            Res::Def(DefKind::Struct | DefKind::Variant, _) | Res::SelfTy(..)
+        )
+    } else {
+        false
+    }
+}
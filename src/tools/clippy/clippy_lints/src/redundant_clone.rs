@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::{span_lint_hir, span_lint_hir_and_then};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{has_drop, is_copy, is_type_diagnostic_item, walk_ptrs_ty_depth};
-use clippy_utils::{fn_has_unsatisfiable_preds, match_def_path, paths};
+use clippy_utils::{fn_has_unsatisfiable_preds, is_lint_allowed, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_data_structures::{fx::FxHashMap, transitive_relation::TransitiveRelation};
 use rustc_errors::Applicability;
@@ -15,7 +15,7 @@
     Mutability,
 };
 use rustc_middle::ty::{self, fold::TypeVisitor, Ty};
-use rustc_mir::dataflow::{Analysis, AnalysisDomain, GenKill, GenKillAnalysis, ResultsCursor};
+use rustc_mir::dataflow::{Analysis, AnalysisDomain, Backward, GenKill, GenKillAnalysis, ResultsCursor};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::{BytePos, Span};
 use rustc_span::sym;
@@ -67,7 +67,44 @@ macro_rules! unwrap_or_continue {
     "`clone()` of an owned value that is going to be dropped immediately"
 }
 
-declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `clone()` calls on a value (or one of its fields) that is
+    /// never used again after the clone.
+    ///
+    /// ### Why is this bad?
+    /// The original value could be moved instead, which avoids an allocation
+    /// and a deallocation for types like `String` or `Vec`.
+    ///
+    /// ### Known problems
+    /// Only local variables and function arguments that are not otherwise
+    /// borrowed at the point of the clone are considered. Values that are
+    /// matched by reference or accessed through a reference are not linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # struct Person { name: String }
+    /// # let people: Vec<Person> = vec![];
+    /// let mut names = Vec::new();
+    /// for person in people {
+    ///     names.push(person.name.clone());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Person { name: String }
+    /// # let people: Vec<Person> = vec![];
+    /// let mut names = Vec::new();
+    /// for person in people {
+    ///     names.push(person.name);
+    /// }
+    /// ```
+    pub REDUNDANT_FINAL_CLONE,
+    perf,
+    "`clone()` of a value that is not used afterwards"
+}
+
+declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE, REDUNDANT_FINAL_CLONE]);
 
 impl<'tcx> LateLintPass<'tcx> for RedundantClone {
     #[allow(clippy::too_many_lines)]
@@ -105,6 +142,9 @@ fn check_fn(
             vis.into_map(cx, maybe_storage_live_result)
         };
 
+        // Blocks where `REDUNDANT_CLONE` applies, so `REDUNDANT_FINAL_CLONE` doesn't lint them again.
+        let mut redundant_clone_blocks = BitSet::new_empty(mir.basic_blocks().len());
+
         for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
             let terminator = bbdata.terminator();
 
@@ -226,6 +266,8 @@ fn check_fn(
                 clone_usage
             };
 
+            redundant_clone_blocks.insert(bb);
+
             let span = terminator.source_info.span;
             let scope = terminator.source_info.scope;
             let node = mir.source_scopes[scope]
@@ -275,6 +317,105 @@ fn check_fn(
                 }
             }
         }
+
+        check_final_clones(cx, mir, &possible_borrower.map, &redundant_clone_blocks);
+    }
+}
+
+/// Lints `clone()` calls on locals which are neither used nor borrowed after the call returns.
+fn check_final_clones<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &'tcx mir::Body<'tcx>,
+    possible_borrowers: &FxHashMap<mir::Local, HybridBitSet<mir::Local>>,
+    skip_blocks: &BitSet<mir::BasicBlock>,
+) {
+    // The liveness of the locals is computed once for the whole body, on the first `clone()` call
+    // which could be linted.
+    let mut maybe_used: Option<ResultsCursor<'_, 'tcx, MaybeUsedLocals>> = None;
+
+    for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
+        let terminator = bbdata.terminator();
+        let span = terminator.source_info.span;
+
+        if span.from_expansion() || skip_blocks.contains(bb) {
+            continue;
+        }
+
+        let target = match &terminator.kind {
+            mir::TerminatorKind::Call {
+                destination: Some((_, target)),
+                ..
+            } => *target,
+            _ => continue,
+        };
+
+        let (fn_def_id, arg, _, _) = unwrap_or_continue!(is_call_with_ref_arg(cx, mir, &terminator.kind));
+        if !match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD) {
+            continue;
+        }
+
+        // `{ arg = &cloned.field; clone(move arg); }`
+        let (cloned, cannot_move_out) = unwrap_or_continue!(find_stmt_assigns_to(cx, mir, arg, true, bb));
+        if cannot_move_out || !matches!(mir.local_kind(cloned), mir::LocalKind::Var | mir::LocalKind::Arg) {
+            continue;
+        }
+
+        let node = mir.source_scopes[terminator.source_info.scope]
+            .local_data
+            .as_ref()
+            .assert_crate_local()
+            .lint_root;
+        if is_lint_allowed(cx, REDUNDANT_FINAL_CLONE, node) {
+            continue;
+        }
+
+        let snip = unwrap_or_continue!(snippet_opt(cx, span));
+        let dot = unwrap_or_continue!(snip.rfind('.'));
+        if snip[dot + 1..].trim() != "clone()" {
+            continue;
+        }
+
+        let maybe_used = maybe_used.get_or_insert_with(|| {
+            MaybeUsedLocals
+                .into_engine(cx.tcx, mir)
+                .pass_name("redundant_final_clone")
+                .iterate_to_fixpoint()
+                .into_results_cursor(mir)
+        });
+        maybe_used.seek_to_block_start(target);
+
+        if maybe_used.contains(cloned) {
+            continue;
+        }
+        if let Some(borrowers) = possible_borrowers.get(&cloned) {
+            // Drops are not counted as uses, so a borrower that is only dropped afterwards could
+            // still read `cloned` in its `Drop` impl.
+            if borrowers
+                .iter()
+                .any(|b| b != arg && (maybe_used.contains(b) || mir.local_decls[b].ty.needs_drop(cx.tcx, cx.param_env)))
+            {
+                continue;
+            }
+        }
+
+        let dot = BytePos(u32::try_from(dot).unwrap());
+        let sugg_span = span.with_lo(span.lo() + dot);
+        span_lint_hir_and_then(
+            cx,
+            REDUNDANT_FINAL_CLONE,
+            node,
+            sugg_span,
+            "redundant clone on the last use of a value",
+            |diag| {
+                diag.span_suggestion(
+                    sugg_span,
+                    "remove this",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+                diag.span_note(span.with_hi(span.lo() + dot), "this value is not used after the clone");
+            },
+        );
     }
 }
 
@@ -509,6 +650,99 @@ fn call_return_effect(
     }
 }
 
+/// Liveness of each local, where dropping a local doesn't count as a use of it: a value that is
+/// only dropped after a `clone()` could have been moved instead.
+struct MaybeUsedLocals;
+
+impl<'tcx> AnalysisDomain<'tcx> for MaybeUsedLocals {
+    type Domain = BitSet<mir::Local>;
+    type Direction = Backward;
+    const NAME: &'static str = "maybe_used_locals";
+
+    fn bottom_value(&self, body: &mir::Body<'tcx>) -> Self::Domain {
+        // bottom = not used
+        BitSet::new_empty(body.local_decls.len())
+    }
+
+    fn initialize_start_block(&self, _: &mir::Body<'tcx>, _: &mut Self::Domain) {}
+}
+
+impl<'tcx> GenKillAnalysis<'tcx> for MaybeUsedLocals {
+    type Idx = mir::Local;
+
+    fn statement_effect(&self, trans: &mut impl GenKill<Self::Idx>, stmt: &mir::Statement<'tcx>, loc: mir::Location) {
+        UsedLocalsVisitor { trans }.visit_statement(stmt, loc);
+    }
+
+    fn terminator_effect(
+        &self,
+        trans: &mut impl GenKill<Self::Idx>,
+        terminator: &mir::Terminator<'tcx>,
+        loc: mir::Location,
+    ) {
+        UsedLocalsVisitor { trans }.visit_terminator(terminator, loc);
+    }
+
+    fn call_return_effect(
+        &self,
+        trans: &mut impl GenKill<Self::Idx>,
+        _block: mir::BasicBlock,
+        _func: &mir::Operand<'tcx>,
+        _args: &[mir::Operand<'tcx>],
+        return_place: mir::Place<'tcx>,
+    ) {
+        if let Some(local) = return_place.as_local() {
+            trans.kill(local);
+        }
+    }
+
+    fn yield_resume_effect(
+        &self,
+        trans: &mut impl GenKill<Self::Idx>,
+        _resume_block: mir::BasicBlock,
+        resume_place: mir::Place<'tcx>,
+    ) {
+        if let Some(local) = resume_place.as_local() {
+            trans.kill(local);
+        }
+    }
+}
+
+struct UsedLocalsVisitor<'a, T> {
+    trans: &'a mut T,
+}
+
+impl<'tcx, T: GenKill<mir::Local>> mir::visit::Visitor<'tcx> for UsedLocalsVisitor<'_, T> {
+    fn visit_place(&mut self, place: &mir::Place<'tcx>, ctx: PlaceContext, loc: mir::Location) {
+        // Like `MaybeLiveLocals`, don't call `super_place` so that the base local isn't visited with a
+        // projection context.
+        self.visit_projection(place.as_ref(), ctx, loc);
+
+        match ctx {
+            // The destination of a call is handled in `call_return_effect`.
+            PlaceContext::NonUse(_)
+            | PlaceContext::MutatingUse(MutatingUseContext::Call | MutatingUseContext::Yield) => {},
+            PlaceContext::MutatingUse(MutatingUseContext::Drop) if !place.is_indirect() => {},
+            // `*p = v` is a use of `p`, and `x.f = v` neither defines nor uses `x`.
+            PlaceContext::MutatingUse(MutatingUseContext::Store) if !place.is_indirect() => {
+                if place.projection.is_empty() {
+                    self.trans.kill(place.local);
+                }
+            },
+            _ => self.trans.gen(place.local),
+        }
+    }
+
+    fn visit_local(&mut self, &local: &mir::Local, ctx: PlaceContext, _: mir::Location) {
+        match ctx {
+            PlaceContext::NonUse(_)
+            | PlaceContext::MutatingUse(MutatingUseContext::Call | MutatingUseContext::Yield) => {},
+            PlaceContext::MutatingUse(MutatingUseContext::Store) => self.trans.kill(local),
+            _ => self.trans.gen(local),
+        }
+    }
+}
+
 /// Collects the possible borrowers of each local.
 /// For example, `b = &a; c = &a;` will make `b` and (transitively) `c`
 /// possible borrowers of `a`.
@@ -1,5 +1,6 @@
 #![warn(clippy::cast_ref_to_mut)]
 #![allow(clippy::no_effect)]
+#![allow(invalid_reference_casting)]
 
 extern "C" {
     // N.B., mutability can be easily incorrect in FFI calls -- as
@@ -1,5 +1,6 @@
 #![warn(clippy::drop_copy, clippy::forget_copy)]
 #![allow(clippy::toplevel_ref_arg, clippy::drop_ref, clippy::forget_ref, unused_mut)]
+#![allow(clippy::redundant_final_clone)]
 
 use std::mem::{drop, forget};
 use std::vec::Vec;
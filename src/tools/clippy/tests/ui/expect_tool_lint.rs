@@ -0,0 +1,17 @@
+#![feature(lint_reasons)]
+#![warn(clippy::needless_return)]
+
+#[expect(clippy::needless_return)]
+fn fulfilled() -> i32 {
+    return 1;
+}
+
+#[expect(clippy::needless_return, reason = "this function used to return early")]
+fn unfulfilled() -> i32 {
+    1
+}
+
+fn main() {
+    fulfilled();
+    unfulfilled();
+}
@@ -159,6 +159,20 @@ fn main() {
     let mut a: WrapperMulti<i32, i64> = Default::default();
     a.i = 42;
 
+    // wrong, tuple struct with all its fields reassigned uses the constructor
+    let mut t: TupleStruct = Default::default();
+    t.1 = 23;
+    t.0 = 42;
+
+    // wrong, tuple struct with some of its fields reassigned
+    let mut t = TupleStruct::default();
+    t.0 = 42;
+
+    // wrong, tuple struct with its fields reassigned out of order by expressions with side effects
+    let mut t: TupleStruct = Default::default();
+    t.1 = "b".len() as i64;
+    t.0 = "a".len() as i32;
+
     // Don't lint in macros
     m! {
         a: 42
@@ -183,3 +197,6 @@ struct WrapperMulti<T, U> {
     i: T,
     j: U,
 }
+
+#[derive(Default)]
+struct TupleStruct(i32, i64);
@@ -1,5 +1,6 @@
 // edition:2018
 #![warn(clippy::future_not_send)]
+#![allow(clippy::redundant_final_clone)]
 
 use std::cell::Cell;
 use std::rc::Rc;
@@ -0,0 +1,57 @@
+// run-rustfix
+#![warn(clippy::init_numbered_fields)]
+
+#[derive(Default)]
+struct TupleStruct(u32, String, u8);
+
+type TupleAlias = TupleStruct;
+
+// This shouldn't lint because it's in a macro
+macro_rules! tuple_struct_init {
+    () => {
+        TupleStruct { 0: 0, 1: String::new(), 2: 0 }
+    };
+}
+
+fn main() {
+    let tuple_struct = TupleStruct::default();
+
+    // This should lint
+    let _ = TupleStruct {
+        0: 1u32,
+        1: 42.to_string(),
+        2: 23u8,
+    };
+
+    // This should also lint and order the fields correctly
+    let _ = TupleStruct {
+        0: 1u32,
+        2: 2u8,
+        1: 3u32.to_string(),
+    };
+
+    // Ok because of default initializer
+    let _ = TupleStruct { 0: 42, ..tuple_struct };
+
+    let _ = TupleStruct {
+        1: 23.to_string(),
+        ..TupleStruct::default()
+    };
+
+    // Ok because putting the fields in order would reorder their side effects
+    let _ = TupleStruct {
+        0: 1u32,
+        2: "a".len() as u8,
+        1: 3u32.to_string(),
+    };
+
+    // Ok because the tuple constructor cannot be called through a type alias
+    let _ = TupleAlias {
+        0: 1u32,
+        1: 42.to_string(),
+        2: 23u8,
+    };
+
+    // Ok because it's in macro
+    let _ = tuple_struct_init!();
+}
@@ -0,0 +1,35 @@
+#![allow(unused)]
+#![warn(clippy::large_stack_frames)]
+
+fn consume<T>(_: &T) {}
+
+fn many_small_arrays() {
+    let x = [0u8; 200_000];
+    let x2 = [0u8; 200_000];
+    let x3 = [0u8; 200_000];
+    let x4 = [0u8; 100_000];
+    consume(&x);
+    consume(&x2);
+    consume(&x3);
+    consume(&x4);
+}
+
+fn large_single() {
+    let x = [0u8; 600_000];
+    consume(&x);
+}
+
+fn small_enough() {
+    let x = [0u8; 100_000];
+    let x2 = [0u8; 100_000];
+    consume(&x);
+    consume(&x2);
+}
+
+// The layout of `[T; 1_000_000]` is unknown, so it doesn't count.
+fn generic<T: Default>() {
+    let x: [T; 1_000_000] = [(); 1_000_000].map(|_| T::default());
+    consume(&x);
+}
+
+fn main() {}
@@ -1,5 +1,5 @@
 #![warn(clippy::manual_flatten)]
-#![allow(clippy::useless_vec)]
+#![allow(clippy::useless_vec, clippy::redundant_final_clone)]
 
 fn main() {
     // Test for loop over implicitly adjusted `Iterator` with `if let` expression
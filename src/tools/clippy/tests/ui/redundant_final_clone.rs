@@ -0,0 +1,112 @@
+// run-rustfix
+
+#![warn(clippy::redundant_final_clone)]
+
+#[derive(Clone)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+enum Entry {
+    Named(String),
+    Anonymous,
+}
+
+struct Guard(String);
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+fn field_in_loop(people: Vec<Person>) -> Vec<String> {
+    let mut names = Vec::new();
+    for person in people {
+        names.push(person.name.clone());
+    }
+    names
+}
+
+fn whole_value_in_loop(people: Vec<Person>) -> Vec<Person> {
+    let mut adults = Vec::new();
+    for person in people {
+        if person.age >= 18 {
+            adults.push(person.clone());
+        }
+    }
+    adults
+}
+
+fn match_binding(entries: Vec<Entry>) -> Vec<String> {
+    let mut names = Vec::new();
+    for entry in entries {
+        match entry {
+            Entry::Named(name) => names.push(name.clone()),
+            Entry::Anonymous => {},
+        }
+    }
+    names
+}
+
+fn used_in_next_iteration() -> Vec<String> {
+    let name = String::from("name");
+    let mut names = Vec::new();
+    for _ in 0..3 {
+        names.push(name.clone());
+    }
+    names
+}
+
+fn used_afterwards(people: Vec<Person>) -> Vec<String> {
+    let mut names = Vec::new();
+    for person in people {
+        names.push(person.name.clone());
+        println!("{}", person.name);
+    }
+    names
+}
+
+fn borrowed_afterwards(people: Vec<Person>) -> Vec<String> {
+    let mut names = Vec::new();
+    for person in people {
+        let r = &person;
+        names.push(person.name.clone());
+        println!("{}", r.age);
+    }
+    names
+}
+
+fn through_reference(people: &[Person]) -> Vec<String> {
+    let mut names = Vec::new();
+    for person in people {
+        names.push(person.name.clone());
+    }
+    names
+}
+
+fn field_of_drop_type(guards: Vec<Guard>) -> Vec<String> {
+    let mut names = Vec::new();
+    for guard in guards {
+        names.push(guard.0.clone());
+    }
+    names
+}
+
+struct Printer<'a>(&'a Person);
+
+impl Drop for Printer<'_> {
+    fn drop(&mut self) {
+        println!("{}", self.0.name);
+    }
+}
+
+fn borrower_dropped_afterwards(people: Vec<Person>) -> Vec<String> {
+    let mut names = Vec::new();
+    for person in people {
+        let _printer = Printer(&person);
+        names.push(person.name.clone());
+    }
+    names
+}
+
+fn main() {}
@@ -511,6 +511,8 @@ pub fn test_opts(config: &Config) -> test::TestOpts {
         options: test::Options::new(),
         time_options: None,
         force_run_in_process: false,
+        shuffle: false,
+        shuffle_seed: None,
     }
 }
 
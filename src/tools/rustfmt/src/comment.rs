@@ -396,24 +396,22 @@ fn consume_same_line_comments(
 /// See <https://doc.rust-lang.org/rustdoc/print.html#attributes>.
 enum CodeBlockAttribute {
     Rust,
-    Ignore,
-    Text,
-    ShouldPanic,
-    NoRun,
-    CompileFail,
+    NotRust,
 }
 
 impl CodeBlockAttribute {
-    fn new(attribute: &str) -> CodeBlockAttribute {
-        match attribute {
-            "rust" | "" => CodeBlockAttribute::Rust,
-            "ignore" => CodeBlockAttribute::Ignore,
-            "text" => CodeBlockAttribute::Text,
-            "should_panic" => CodeBlockAttribute::ShouldPanic,
-            "no_run" => CodeBlockAttribute::NoRun,
-            "compile_fail" => CodeBlockAttribute::CompileFail,
-            _ => CodeBlockAttribute::Text,
+    /// Parses a comma separated list of attributes. Returns `Rust` only if all of them are
+    /// attributes of Rust code blocks that are expected to parse, so that code marked as
+    /// `ignore`, `text`, `compile_fail` or in another language is left untouched.
+    fn new(attributes: &str) -> CodeBlockAttribute {
+        for attribute in attributes.split(',') {
+            match attribute.trim() {
+                "" | "rust" | "should_panic" | "no_run" | "edition2015" | "edition2018"
+                | "edition2021" => (),
+                _ => return CodeBlockAttribute::NotRust,
+            }
         }
+        CodeBlockAttribute::Rust
     }
 }
 
@@ -647,13 +645,22 @@ fn handle_line(
         } else if self.code_block_attr.is_some() {
             if line.starts_with("```") {
                 let code_block = match self.code_block_attr.as_ref().unwrap() {
-                    CodeBlockAttribute::Ignore | CodeBlockAttribute::Text => {
+                    CodeBlockAttribute::NotRust => {
                         trim_custom_comment_prefix(&self.code_block_buffer)
                     }
                     _ if self.code_block_buffer.is_empty() => String::new(),
                     _ => {
                         let mut config = self.fmt.config.clone();
                         config.set().wrap_comments(false);
+                        // The formatted code is placed after the comment prefix, so it only
+                        // gets the width that is left of `max_width` on the comment lines. The
+                        // code block is formatted inside a `fn main` wrapper whose indentation
+                        // is removed afterwards, so that indentation does not count.
+                        let code_width = config
+                            .max_width()
+                            .saturating_sub(self.fmt_indent.width() + self.line_start.len())
+                            + config.tab_spaces();
+                        config.set().max_width(code_width);
                         if config.format_code_in_doc_comments() {
                             if let Some(s) =
                                 crate::format_code_block(&self.code_block_buffer, &config, false)
@@ -81,9 +81,9 @@
     // Imports
     imports_indent: IndentStyle, IndentStyle::Block, false, "Indent of imports";
     imports_layout: ListTactic, ListTactic::Mixed, false, "Item layout inside a import block";
-    imports_granularity: ImportGranularity, ImportGranularity::Preserve, false,
+    imports_granularity: ImportGranularity, ImportGranularity::Preserve, true,
         "Merge or split imports to the provided granularity";
-    group_imports: GroupImportsTactic, GroupImportsTactic::Preserve, false,
+    group_imports: GroupImportsTactic, GroupImportsTactic::Preserve, true,
         "Controls the strategy for how imports are grouped together";
     merge_imports: bool, false, false, "(deprecated: use imports_granularity instead)";
 
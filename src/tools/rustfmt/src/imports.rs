@@ -162,7 +162,7 @@ fn from_path_segment(
 pub(crate) fn merge_use_trees(use_trees: Vec<UseTree>, merge_by: SharedPrefix) -> Vec<UseTree> {
     let mut result = Vec::with_capacity(use_trees.len());
     for use_tree in use_trees {
-        if use_tree.has_comment() || use_tree.attrs.is_some() {
+        if use_tree.contains_comment() || use_tree.attrs.is_some() {
             result.push(use_tree);
             continue;
         }
@@ -523,6 +523,17 @@ fn has_comment(&self) -> bool {
         self.list_item.as_ref().map_or(false, ListItem::has_comment)
     }
 
+    /// Returns `true` if this use tree or any of its nested use trees has a comment attached.
+    fn contains_comment(&self) -> bool {
+        if self.has_comment() {
+            return true;
+        }
+        match self.path.last() {
+            Some(UseSegment::List(list)) => list.iter().any(UseTree::contains_comment),
+            _ => false,
+        }
+    }
+
     fn same_visibility(&self, other: &UseTree) -> bool {
         match (&self.visibility, &other.visibility) {
             (
@@ -549,6 +560,7 @@ fn share_prefix(&self, other: &UseTree, shared_prefix: SharedPrefix) -> bool {
         if self.path.is_empty()
             || other.path.is_empty()
             || self.attrs.is_some()
+            || self.contains_comment()
             || !self.same_visibility(other)
         {
             false
@@ -563,7 +575,8 @@ fn share_prefix(&self, other: &UseTree, shared_prefix: SharedPrefix) -> bool {
     }
 
     fn flatten(self) -> Vec<UseTree> {
-        if self.path.is_empty() {
+        // Comments cannot be split between the flattened use trees, so keep them in place.
+        if self.path.is_empty() || self.contains_comment() {
             return vec![self];
         }
         match self.path.clone().last().unwrap() {
@@ -584,7 +597,8 @@ fn flatten(self) -> Vec<UseTree> {
                             span: self.span,
                             list_item: None,
                             visibility: self.visibility.clone(),
-                            attrs: None,
+                            // Keep attributes like `#[cfg]` on every flattened use tree.
+                            attrs: self.attrs.clone(),
                         });
                     }
                 }
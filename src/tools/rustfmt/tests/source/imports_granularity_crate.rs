@@ -35,3 +35,10 @@
 
 use {k::{a, b}, l::{a, b}};
 use {k::{c, d}, l::{c, d}};
+
+#[cfg(unix)]
+use m::x;
+use m::y; // y comment
+use m::z;
+use m::{v, /* inner */ w};
+use m::q;
@@ -4,3 +4,8 @@
 use a::{f::g, h::{i, j}};
 use a::{l::{self, m, n::o, p::*}};
 use a::q::{self};
+
+#[cfg(unix)]
+use b::{r, s};
+use b::{t, u}; // comment
+use b::{v, /* inner */ w};
@@ -16,3 +16,10 @@
     c::d,
     e::f,
 };
+
+#[cfg(unix)]
+use baz::x;
+use baz::y; // y comment
+use baz::z;
+use baz::{v, /* inner */ w};
+use baz::q;
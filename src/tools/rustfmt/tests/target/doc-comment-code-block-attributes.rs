@@ -0,0 +1,30 @@
+// rustfmt-format_code_in_doc_comments: true
+
+/// ```rust,no_run
+/// let x = 1;
+/// ```
+///
+/// ```should_panic,edition2018
+/// let y = vec![1, 2];
+/// ```
+///
+/// ```rust,ignore
+/// let z=1;
+/// ```
+///
+/// ```ignore (needs a network connection)
+/// let z=1;
+/// ```
+///
+/// ```compile_fail
+/// let z:u32="a";
+/// ```
+///
+/// ```text
+/// let z=1;
+/// ```
+///
+/// ```python
+/// x=[1,2]
+/// ```
+fn foo() {}
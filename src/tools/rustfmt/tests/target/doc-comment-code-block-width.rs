@@ -0,0 +1,9 @@
+// rustfmt-format_code_in_doc_comments: true
+
+impl Foo {
+    /// ```
+    /// let result =
+    ///     some_function_with_a_long_name(first_argument, second_argument, third_argument);
+    /// ```
+    fn foo() {}
+}
@@ -0,0 +1,20 @@
+// rustfmt-imports_granularity: Item
+
+use a::b;
+use a::c;
+use a::d;
+use a::f::g;
+use a::h::i;
+use a::h::j;
+use a::l::m;
+use a::l::n::o;
+use a::l::p::*;
+use a::l::{self};
+use a::q::{self};
+
+#[cfg(unix)]
+use b::r;
+#[cfg(unix)]
+use b::s;
+use b::{t, u}; // comment
+use b::{v, /* inner */ w};